@@ -0,0 +1,376 @@
+//! Derive macro for the `markdown-builder` crate.
+//!
+//! Use it through the `derive` feature of `markdown-builder`, which re-exports
+//! [ToMarkdown](derive.ToMarkdown.html).
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Expr, ExprLit,
+    Fields, GenericArgument, Lit, LitStr, Meta, PathArguments, Type,
+};
+
+/// Derives `ToMarkdown` for a struct or an enum.
+///
+/// Structs are rendered as a section titled after the struct, containing its
+/// doc comment and a table of its fields along with their doc comments. Enums
+/// are rendered as a section describing the current variant. Field values are
+/// rendered using `MarkdownValue`.
+///
+/// # Attributes
+///
+/// - `#[markdown(rename = "name")]`: Uses another name for the type or field.
+/// - `#[markdown(skip)]`: Leaves the field out.
+/// - `#[markdown(code)]`: Renders the field value as inline code.
+/// - `#[markdown(nested)]`: Renders the field, which has to implement
+///   `ToMarkdown`, as a sub-section. A `Vec` of such values is rendered as a
+///   table with one row per element.
+#[proc_macro_derive(ToMarkdown, attributes(markdown))]
+pub fn derive_to_markdown(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The options given using `#[markdown(...)]`.
+#[derive(Default)]
+struct Options {
+    rename: Option<String>,
+    skip: bool,
+    code: bool,
+    nested: bool,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("markdown")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    options.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("skip") {
+                    options.skip = true;
+                } else if meta.path.is_ident("code") {
+                    options.code = true;
+                } else if meta.path.is_ident("nested") {
+                    options.nested = true;
+                } else {
+                    return Err(meta.error("unknown markdown attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Joins the doc comments into a single line.
+fn docs(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(text),
+                    ..
+                }) => Some(text.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    (!lines.is_empty()).then(|| lines.join(" "))
+}
+
+/// Returns `T` if the type is a `Vec<T>`.
+fn vec_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// A field along with the expression used to access it.
+struct Field<'a> {
+    name: String,
+    access: TokenStream,
+    ty: &'a Type,
+    doc: Option<String>,
+    options: Options,
+}
+
+/// Collects the fields that are not skipped.
+///
+/// `access` returns the expression evaluating to a reference to the field,
+/// given its identifier or its index.
+fn collect_fields(
+    fields: &Fields,
+    access: impl Fn(&syn::Field, usize) -> TokenStream,
+) -> syn::Result<Vec<Field<'_>>> {
+    let mut collected = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let options = Options::parse(&field.attrs)?;
+        if options.skip {
+            continue;
+        }
+
+        let name = options.rename.clone().unwrap_or_else(|| {
+            field
+                .ident
+                .as_ref()
+                .map_or_else(|| index.to_string(), ToString::to_string)
+        });
+
+        collected.push(Field {
+            name,
+            access: access(field, index),
+            ty: &field.ty,
+            doc: docs(&field.attrs),
+            options,
+        });
+    }
+
+    Ok(collected)
+}
+
+/// Returns the expression rendering a plain field to a `String`.
+fn value(field: &Field) -> TokenStream {
+    let access = &field.access;
+    let value = quote! { ::markdown_builder::MarkdownValue::to_markdown_value(#access) };
+
+    if field.options.code {
+        quote! {{
+            let value = #value;
+            if value.is_empty() {
+                value
+            } else {
+                ::markdown_builder::Inline::to_inline(&value)
+            }
+        }}
+    } else {
+        value
+    }
+}
+
+/// Returns the statements adding the fields to `section`.
+fn add_fields(fields: &[Field]) -> TokenStream {
+    let plain: Vec<&Field> = fields
+        .iter()
+        .filter(|field| !field.options.nested)
+        .collect();
+    let described = plain.iter().any(|field| field.doc.is_some());
+
+    let headers = if described {
+        quote! { vec!["Field", "Value", "Description"] }
+    } else {
+        quote! { vec!["Field", "Value"] }
+    };
+    let rows = plain.iter().map(|field| {
+        let name = &field.name;
+        let value = value(field);
+        let doc = field.doc.clone().unwrap_or_default();
+        if described {
+            quote! { table.add_row(vec![::std::string::String::from(#name), #value, ::std::string::String::from(#doc)]); }
+        } else {
+            quote! { table.add_row(vec![::std::string::String::from(#name), #value]); }
+        }
+    });
+
+    let nested = fields.iter().filter(|field| field.options.nested).map(|field| {
+        let name = &field.name;
+        let access = &field.access;
+        let doc = field.doc.as_ref().map(|doc| quote! { nested.paragraph(#doc); });
+
+        if let Some(inner) = vec_inner(field.ty) {
+            quote! {
+                section.section(#name, |nested| {
+                    #doc
+                    nested.add(<#inner as ::markdown_builder::ToMarkdown>::to_table(#access));
+                });
+            }
+        } else {
+            let doc = field.doc.as_ref().map(|doc| {
+                quote! {
+                    nested.children.insert(
+                        0,
+                        ::markdown_builder::Node::Paragraph(::markdown_builder::Paragraph::from(#doc)),
+                    );
+                }
+            });
+            quote! {{
+                let mut nested = ::markdown_builder::ToMarkdown::to_section(#access);
+                nested.header.text = ::std::string::String::from(#name);
+                #doc
+                section.add_section(nested);
+            }}
+        }
+    });
+
+    let table = (!plain.is_empty()).then(|| {
+        quote! {
+            let mut table = ::markdown_builder::Table::from(#headers);
+            #(#rows)*
+            section.add(table);
+        }
+    });
+
+    quote! {
+        #table
+        #(#nested)*
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let options = Options::parse(&input.attrs)?;
+    let title = options.rename.unwrap_or_else(|| ident.to_string());
+    let doc = docs(&input.attrs).map(|doc| quote! { section.paragraph(#doc); });
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    match &input.data {
+        Data::Struct(data) => {
+            let fields = collect_fields(&data.fields, |field, index| match &field.ident {
+                Some(ident) => quote! { &self.#ident },
+                None => {
+                    let index = syn::Index::from(index);
+                    quote! { &self.#index }
+                }
+            })?;
+            let body = add_fields(&fields);
+            let plain: Vec<&Field> = fields
+                .iter()
+                .filter(|field| !field.options.nested)
+                .collect();
+            let headers = plain.iter().map(|field| &field.name);
+            let cells = plain.iter().map(|field| value(field));
+
+            Ok(quote! {
+                impl #impl_generics ::markdown_builder::ToMarkdown for #ident #ty_generics #where_clause {
+                    fn to_section(&self) -> ::markdown_builder::Section {
+                        let mut section = ::markdown_builder::Section::new(#title);
+                        #doc
+                        #body
+                        section
+                    }
+
+                    fn table_headers() -> ::std::vec::Vec<::std::string::String> {
+                        vec![#(::std::string::String::from(#headers)),*]
+                    }
+
+                    fn table_row(&self) -> ::std::vec::Vec<::std::string::String> {
+                        vec![#(#cells),*]
+                    }
+                }
+            })
+        }
+        Data::Enum(data) => {
+            let mut sections = Vec::new();
+            let mut names = Vec::new();
+
+            for variant in &data.variants {
+                let variant_ident = &variant.ident;
+                let variant_options = Options::parse(&variant.attrs)?;
+                let name = variant_options
+                    .rename
+                    .unwrap_or_else(|| variant_ident.to_string());
+                let variant_doc =
+                    docs(&variant.attrs).map(|doc| quote! { section.paragraph(#doc); });
+
+                let bindings: Vec<_> = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        field
+                            .ident
+                            .clone()
+                            .unwrap_or_else(|| format_ident!("field_{}", index))
+                    })
+                    .collect();
+                let pattern = match &variant.fields {
+                    Fields::Named(_) => quote! { Self::#variant_ident { #(#bindings),* } },
+                    Fields::Unnamed(_) => quote! { Self::#variant_ident(#(#bindings),*) },
+                    Fields::Unit => quote! { Self::#variant_ident },
+                };
+                let wildcard = match &variant.fields {
+                    Fields::Named(_) => quote! { Self::#variant_ident { .. } },
+                    Fields::Unnamed(_) => quote! { Self::#variant_ident(..) },
+                    Fields::Unit => quote! { Self::#variant_ident },
+                };
+
+                let fields = collect_fields(&variant.fields, |_, index| {
+                    let binding = &bindings[index];
+                    quote! { #binding }
+                })?;
+                let body = add_fields(&fields);
+
+                sections.push(quote! {
+                    #[allow(unused_variables)]
+                    #pattern => {
+                        section.paragraph(::markdown_builder::Inline::to_inline(&#name));
+                        #variant_doc
+                        #body
+                    }
+                });
+                names.push(quote! { #wildcard => #name });
+            }
+
+            if data.variants.is_empty() {
+                return Err(Error::new(
+                    input.span(),
+                    "ToMarkdown cannot be derived for enums without variants",
+                ));
+            }
+
+            Ok(quote! {
+                impl #impl_generics ::markdown_builder::ToMarkdown for #ident #ty_generics #where_clause {
+                    fn to_section(&self) -> ::markdown_builder::Section {
+                        let mut section = ::markdown_builder::Section::new(#title);
+                        #doc
+                        match self {
+                            #(#sections)*
+                        }
+                        section
+                    }
+
+                    fn table_headers() -> ::std::vec::Vec<::std::string::String> {
+                        vec![::std::string::String::from(#title)]
+                    }
+
+                    fn table_row(&self) -> ::std::vec::Vec<::std::string::String> {
+                        vec![::markdown_builder::MarkdownValue::to_markdown_value(self)]
+                    }
+                }
+
+                impl #impl_generics ::markdown_builder::MarkdownValue for #ident #ty_generics #where_clause {
+                    fn to_markdown_value(&self) -> ::std::string::String {
+                        ::std::string::String::from(match self {
+                            #(#names),*
+                        })
+                    }
+                }
+            })
+        }
+        Data::Union(_) => Err(Error::new(
+            input.span(),
+            "ToMarkdown cannot be derived for unions",
+        )),
+    }
+}
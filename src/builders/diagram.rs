@@ -0,0 +1,346 @@
+use crate::types::diagram::Diagram;
+
+/// The direction a flowchart is laid out in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FlowDirection {
+    /// From top to bottom.
+    #[default]
+    TopDown,
+    /// From bottom to top.
+    BottomUp,
+    /// From left to right.
+    LeftRight,
+    /// From right to left.
+    RightLeft,
+}
+
+impl FlowDirection {
+    fn keyword(self) -> &'static str {
+        match self {
+            Self::TopDown => "TD",
+            Self::BottomUp => "BT",
+            Self::LeftRight => "LR",
+            Self::RightLeft => "RL",
+        }
+    }
+}
+
+/// The shape of a flowchart node.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NodeShape {
+    /// A rectangle.
+    #[default]
+    Rectangle,
+    /// A rectangle with rounded corners.
+    Rounded,
+    /// A pill shaped node.
+    Stadium,
+    /// A circle.
+    Circle,
+    /// A rhombus, e.g. for decisions.
+    Rhombus,
+}
+
+impl NodeShape {
+    fn delimiters(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Rectangle => ("[", "]"),
+            Self::Rounded => ("(", ")"),
+            Self::Stadium => ("([", "])"),
+            Self::Circle => ("((", "))"),
+            Self::Rhombus => ("{", "}"),
+        }
+    }
+}
+
+/// Escapes text using Mermaid's entity codes, so it can be used inside of a
+/// quoted label.
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '#' => "#35;".to_string(),
+            '"' => "#quot;".to_string(),
+            ';' => "#59;".to_string(),
+            '\n' => "<br>".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Panics if `id` cannot be used as the id of a Mermaid node.
+fn check_id(id: &str) {
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        panic!(
+            "Invalid diagram id {:?}, use letters, digits, '_' and '-'",
+            id
+        )
+    }
+
+    if id.eq_ignore_ascii_case("end") {
+        panic!("Attempt to use the reserved word {:?} as diagram id", id)
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FlowchartBuilder {
+    direction: FlowDirection,
+    statements: Vec<String>,
+}
+
+impl FlowchartBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn direction(mut self, direction: FlowDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Adds a rectangular node.
+    pub fn node(self, id: impl Into<String>, label: impl AsRef<str>) -> Self {
+        self.shaped_node(id, label, NodeShape::Rectangle)
+    }
+
+    /// Adds a node with the given shape.
+    pub fn shaped_node(
+        mut self,
+        id: impl Into<String>,
+        label: impl AsRef<str>,
+        shape: NodeShape,
+    ) -> Self {
+        let id = id.into();
+        check_id(&id);
+
+        let (open, close) = shape.delimiters();
+        self.statements.push(format!(
+            "{}{}\"{}\"{}",
+            id,
+            open,
+            escape(label.as_ref()),
+            close
+        ));
+        self
+    }
+
+    /// Adds an arrow between two nodes.
+    pub fn edge(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        let (from, to) = (from.into(), to.into());
+        check_id(&from);
+        check_id(&to);
+
+        self.statements.push(format!("{} --> {}", from, to));
+        self
+    }
+
+    /// Adds a labeled arrow between two nodes.
+    pub fn labeled_edge(
+        mut self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        label: impl AsRef<str>,
+    ) -> Self {
+        let (from, to) = (from.into(), to.into());
+        check_id(&from);
+        check_id(&to);
+
+        self.statements.push(format!(
+            "{} -->|\"{}\"| {}",
+            from,
+            escape(label.as_ref()),
+            to
+        ));
+        self
+    }
+
+    /// Adds a subgraph grouping the nodes added by `build`.
+    pub fn subgraph(
+        mut self,
+        id: impl Into<String>,
+        title: impl AsRef<str>,
+        build: impl FnOnce(FlowchartBuilder) -> FlowchartBuilder,
+    ) -> Self {
+        let id = id.into();
+        check_id(&id);
+
+        let inner = build(FlowchartBuilder::new());
+        self.statements
+            .push(format!("subgraph {}[\"{}\"]", id, escape(title.as_ref())));
+        if inner.direction != FlowDirection::default() {
+            self.statements
+                .push(format!("    direction {}", inner.direction.keyword()));
+        }
+        for statement in inner.statements {
+            for line in statement.lines() {
+                self.statements.push(format!("    {}", line));
+            }
+        }
+        self.statements.push("end".to_string());
+        self
+    }
+
+    pub fn build(self) -> Diagram {
+        let mut source = format!("flowchart {}", self.direction.keyword());
+        for statement in self.statements {
+            source.push_str("\n    ");
+            source.push_str(&statement);
+        }
+
+        Diagram::mermaid(source)
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SequenceBuilder {
+    statements: Vec<String>,
+}
+
+impl SequenceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares a participant, shown as a box labeled `label`.
+    pub fn participant(mut self, id: impl Into<String>, label: impl AsRef<str>) -> Self {
+        let id = id.into();
+        check_id(&id);
+
+        self.statements
+            .push(format!("participant {} as {}", id, escape(label.as_ref())));
+        self
+    }
+
+    /// Declares a participant, shown as a stick figure labeled `label`.
+    pub fn actor(mut self, id: impl Into<String>, label: impl AsRef<str>) -> Self {
+        let id = id.into();
+        check_id(&id);
+
+        self.statements
+            .push(format!("actor {} as {}", id, escape(label.as_ref())));
+        self
+    }
+
+    /// Adds a message, drawn as a solid arrow.
+    pub fn message(
+        self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        text: impl AsRef<str>,
+    ) -> Self {
+        self.arrow(from, to, "->>", text.as_ref())
+    }
+
+    /// Adds a reply, drawn as a dotted arrow.
+    pub fn reply(
+        self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        text: impl AsRef<str>,
+    ) -> Self {
+        self.arrow(from, to, "-->>", text.as_ref())
+    }
+
+    fn arrow(
+        mut self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        arrow: &str,
+        text: &str,
+    ) -> Self {
+        let (from, to) = (from.into(), to.into());
+        check_id(&from);
+        check_id(&to);
+
+        self.statements
+            .push(format!("{}{}{}: {}", from, arrow, to, escape(text)));
+        self
+    }
+
+    pub fn build(self) -> Diagram {
+        let mut source = "sequenceDiagram".to_string();
+        for statement in self.statements {
+            source.push_str("\n    ");
+            source.push_str(&statement);
+        }
+
+        Diagram::mermaid(source)
+    }
+}
+
+impl Diagram {
+    pub fn flowchart() -> FlowchartBuilder {
+        Default::default()
+    }
+
+    pub fn sequence() -> SequenceBuilder {
+        Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MarkdownElement;
+
+    #[test]
+    fn test_flowchart_builder() {
+        let diagram = Diagram::flowchart()
+            .direction(FlowDirection::LeftRight)
+            .node("src", "Source \"code\"")
+            .shaped_node("ok", "Passes #1?", NodeShape::Rhombus)
+            .labeled_edge("src", "ok", "cargo test; cargo clippy")
+            .subgraph("ci", "CI", |ci| {
+                ci.node("build", "Build").edge("build", "ok")
+            })
+            .build();
+
+        assert_eq!(
+            diagram.render(),
+            "```mermaid\n\
+            flowchart LR\n    \
+            src[\"Source #quot;code#quot;\"]\n    \
+            ok{\"Passes #35;1?\"}\n    \
+            src -->|\"cargo test#59; cargo clippy\"| ok\n    \
+            subgraph ci[\"CI\"]\n        \
+            build[\"Build\"]\n        \
+            build --> ok\n    \
+            end\n\
+            ```\n"
+        );
+    }
+
+    #[test]
+    fn test_sequence_builder() {
+        let diagram = Diagram::sequence()
+            .actor("user", "User")
+            .participant("api", "API")
+            .message("user", "api", "GET /items")
+            .reply("api", "user", "200 OK")
+            .build();
+
+        assert_eq!(
+            diagram.source,
+            "sequenceDiagram\n    \
+            actor user as User\n    \
+            participant api as API\n    \
+            user->>api: GET /items\n    \
+            api-->>user: 200 OK"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_id_panic() {
+        Diagram::flowchart().node("a b", "A");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reserved_id_panic() {
+        Diagram::flowchart().edge("start", "end");
+    }
+}
@@ -1,9 +1,14 @@
-use crate::types::image::Image;
+use crate::types::{image::Image, table::Alignment};
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct ImageBuilder {
     text: Option<String>,
     url: Option<String>,
+    title: Option<String>,
+    width: Option<String>,
+    height: Option<String>,
+    align: Alignment,
+    link: Option<String>,
     footer: bool,
 }
 
@@ -32,6 +37,33 @@ impl ImageBuilder {
         self
     }
 
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<String>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    pub fn align(mut self, align: Alignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Wraps the image in a link to `url`, also using a footer reference if
+    /// the image does.
+    pub fn linked_to(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+
     pub fn build(self) -> Image {
         if self.url.is_none() {
             panic!("Attempt to build image without source URL")
@@ -41,11 +73,17 @@ impl ImageBuilder {
             panic!("Attempt to bulid image with URL in footer without image alt-text");
         }
 
-        Image::from(
+        let mut image = Image::from(
             self.url.unwrap(),
             self.text.unwrap_or("".into()),
             self.footer,
-        )
+        );
+        image.title = self.title;
+        image.width = self.width;
+        image.height = self.height;
+        image.align = self.align;
+        image.link = self.link;
+        image
     }
 }
 
@@ -67,7 +105,7 @@ mod tests {
             .footer()
             .build();
 
-        assert_eq!(image.footer, true);
+        assert!(image.footer);
         assert_eq!(image.url, "https://example.com/picture.png");
         assert_eq!(image.text, "A cute picture of a sandcat");
     }
@@ -80,7 +118,7 @@ mod tests {
             .set_footer(true)
             .build();
 
-        assert_eq!(footer.footer, true);
+        assert!(footer.footer);
         assert_eq!(footer.url, "https://example.com/picture.png");
         assert_eq!(footer.text, "A cute picture of a sandcat");
 
@@ -90,11 +128,37 @@ mod tests {
             .set_footer(false)
             .build();
 
-        assert_eq!(no_footer.footer, false);
+        assert!(!no_footer.footer);
         assert_eq!(no_footer.url, "https://example.com/picture.png");
         assert_eq!(no_footer.text, "A cute picture of a sandcat");
     }
 
+    #[test]
+    fn test_image_builder_title() {
+        let image = Image::builder()
+            .url("https://example.com/picture.png")
+            .title("A sandcat")
+            .build();
+
+        assert_eq!(image.title.as_deref(), Some("A sandcat"));
+    }
+
+    #[test]
+    fn test_image_builder_size_align_link() {
+        let image = Image::builder()
+            .url("https://example.com/picture.png")
+            .width("50%")
+            .height("120")
+            .align(Alignment::Right)
+            .linked_to("https://example.com")
+            .build();
+
+        assert_eq!(image.width.as_deref(), Some("50%"));
+        assert_eq!(image.height.as_deref(), Some("120"));
+        assert_eq!(image.align, Alignment::Right);
+        assert_eq!(image.link.as_deref(), Some("https://example.com"));
+    }
+
     #[test]
     #[should_panic]
     fn test_image_builder_no_url_panic() {
@@ -4,6 +4,7 @@ use crate::types::link::Link;
 pub struct LinkBuilder {
     text: Option<String>,
     url: Option<String>,
+    title: Option<String>,
     footer: bool,
     inlined: bool,
 }
@@ -23,6 +24,11 @@ impl LinkBuilder {
         self
     }
 
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
     pub fn footer(mut self) -> Self {
         self.footer = true;
         self
@@ -52,12 +58,14 @@ impl LinkBuilder {
             panic!("Attempt to build link without text")
         }
 
-        Link::from(
+        let mut link = Link::from(
             self.url.unwrap(),
             self.text.unwrap(),
             self.footer,
             self.inlined,
-        )
+        );
+        link.title = self.title;
+        link
     }
 }
 
@@ -80,8 +88,8 @@ mod tests {
 
         assert_eq!(link.url, "https://www.rust-lang.org/");
         assert_eq!(link.text, "A cool website");
-        assert_eq!(link.footer, false);
-        assert_eq!(link.inlined, false);
+        assert!(!link.footer);
+        assert!(!link.inlined);
     }
 
     #[test]
@@ -94,8 +102,8 @@ mod tests {
 
         assert_eq!(link.url, "https://www.rust-lang.org/");
         assert_eq!(link.text, "A cool website");
-        assert_eq!(link.footer, true);
-        assert_eq!(link.inlined, false);
+        assert!(link.footer);
+        assert!(!link.inlined);
     }
 
     #[test]
@@ -108,8 +116,8 @@ mod tests {
 
         assert_eq!(link.url, "https://www.rust-lang.org/");
         assert_eq!(link.text, "A cool website");
-        assert_eq!(link.footer, true);
-        assert_eq!(link.inlined, false);
+        assert!(link.footer);
+        assert!(!link.inlined);
 
         let link_footerless = Link::builder()
             .url("https://www.rust-lang.org/")
@@ -119,8 +127,8 @@ mod tests {
 
         assert_eq!(link_footerless.url, "https://www.rust-lang.org/");
         assert_eq!(link_footerless.text, "A cool website");
-        assert_eq!(link_footerless.footer, false);
-        assert_eq!(link_footerless.inlined, false);
+        assert!(!link_footerless.footer);
+        assert!(!link_footerless.inlined);
     }
 
     #[test]
@@ -133,8 +141,8 @@ mod tests {
 
         assert_eq!(link.url, "https://www.rust-lang.org/");
         assert_eq!(link.text, "A cool website");
-        assert_eq!(link.footer, false);
-        assert_eq!(link.inlined, true);
+        assert!(!link.footer);
+        assert!(link.inlined);
     }
 
     #[test]
@@ -148,8 +156,8 @@ mod tests {
 
         assert_eq!(link_inlined.url, "https://www.rust-lang.org/");
         assert_eq!(link_inlined.text, "A cool website");
-        assert_eq!(link_inlined.footer, true);
-        assert_eq!(link_inlined.inlined, true);
+        assert!(link_inlined.footer);
+        assert!(link_inlined.inlined);
 
         let link = Link::builder()
             .url("https://www.rust-lang.org/")
@@ -160,8 +168,19 @@ mod tests {
 
         assert_eq!(link.url, "https://www.rust-lang.org/");
         assert_eq!(link.text, "A cool website");
-        assert_eq!(link.footer, false);
-        assert_eq!(link.inlined, false);
+        assert!(!link.footer);
+        assert!(!link.inlined);
+    }
+
+    #[test]
+    fn test_link_builder_title() {
+        let link = Link::builder()
+            .url("https://www.rust-lang.org/")
+            .text("Rust")
+            .title("The Rust website")
+            .build();
+
+        assert_eq!(link.title.as_deref(), Some("The Rust website"));
     }
 
     #[test]
@@ -1,12 +1,21 @@
-use crate::types::{
-    checkbox::Checkbox,
-    list::{List, ListItem},
+use crate::{
+    traits::MarkdownElement,
+    types::{
+        checkbox::{Checkbox, Task},
+        include::Include,
+        list::{List, ListDelimiter, ListItem},
+        markdown::Markdown,
+        node::Node,
+    },
 };
 
 #[derive(Clone, Debug, Default)]
 pub struct ListBuilder {
     items: Vec<ListItem>,
-    has_checkboxes: bool,
+    start: Option<usize>,
+    delimiter: ListDelimiter,
+    lazy: bool,
+    loose: bool,
 }
 
 impl ListBuilder {
@@ -14,15 +23,58 @@ impl ListBuilder {
         Self::default()
     }
 
-    pub fn append(mut self, item: impl Into<ListItem>) -> Self {
-        self.items.push(item.into());
+    pub fn append(mut self, item: impl MarkdownElement + 'static) -> Self {
+        self.items.push(Node::from_element(item));
+        self
+    }
+
+    /// Adds an item made of several blocks, e.g. a paragraph followed by a
+    /// code block and a nested list.
+    ///
+    /// The blocks are added to an empty document, which is embedded using an
+    /// [Include](../../types/include/struct.Include.html). They are separated
+    /// like the blocks of a document, so they do not need trailing line breaks.
+    pub fn item(mut self, build: impl FnOnce(&mut Markdown)) -> Self {
+        let mut blocks = Markdown::new();
+        build(&mut blocks);
+        self.items.push(Include::from(blocks).into());
         self
     }
 
     /// Adds a checkbox using [checkbox::Checkbox].
     pub fn checkbox(mut self, item: impl Into<String>, checked: bool) -> Self {
         self.items.push(Checkbox::from(item, checked).into());
-        self.has_checkboxes = true;
+        self
+    }
+
+    /// Adds a checkbox with nested subtasks using
+    /// [Task](../../types/checkbox/struct.Task.html).
+    pub fn task(mut self, item: impl Into<String>, checked: bool, subtasks: List) -> Self {
+        self.items.push(Task::from(item, checked, subtasks).into());
+        self
+    }
+
+    /// Sets the number of the first item, used by [ordered](#method.ordered).
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn delimiter(mut self, delimiter: ListDelimiter) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Numbers every item using the start number, used by
+    /// [ordered](#method.ordered).
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    /// Separates the items using blank lines.
+    pub fn loose(mut self) -> Self {
+        self.loose = true;
         self
     }
 
@@ -31,11 +83,14 @@ impl ListBuilder {
             panic!("Attempt to bulid list without contents");
         }
 
-        if self.has_checkboxes {
-            panic!("Attempt to build ordered list with checkboxes")
+        let mut list = List::ordered_with(self.items);
+        list.set_delimiter(self.delimiter)
+            .set_lazy(self.lazy)
+            .set_loose(self.loose);
+        if let Some(start) = self.start {
+            list.set_start(start);
         }
-
-        List::ordered_with(self.items)
+        list
     }
 
     pub fn unordered(self) -> List {
@@ -43,7 +98,9 @@ impl ListBuilder {
             panic!("Attempt to bulid list without contents");
         }
 
-        List::unordered_with(self.items)
+        let mut list = List::unordered_with(self.items);
+        list.set_loose(self.loose);
+        list
     }
 }
 
@@ -56,7 +113,7 @@ impl List {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{ListVariant, MarkdownElement, Paragraph};
+    use crate::{CodeBlock, ListVariant, Paragraph};
 
     #[test]
     fn test_ordered_paragraphs() {
@@ -112,11 +169,82 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn test_list_builder_ordered_checkbox_panic() {
-        List::builder()
+    fn test_ordered_checkboxes() {
+        let list = List::builder()
             .checkbox("Hello world", false)
             .checkbox("Checked", true)
             .ordered();
+
+        assert_eq!(list.render(), "1. [ ] Hello world\n2. [x] Checked\n");
+    }
+
+    #[test]
+    fn test_ordered_numbering() {
+        let list = List::builder()
+            .append("Install")
+            .append("Run")
+            .start(3)
+            .delimiter(ListDelimiter::Parenthesis)
+            .lazy()
+            .ordered();
+
+        assert_eq!(list.render(), "3) Install\n3) Run\n");
+    }
+
+    #[test]
+    fn test_multi_block_items() {
+        let list = List::builder()
+            .item(|item| {
+                item.paragraph("Install the crate:")
+                    .add("cargo add markdown-builder".to_code_block_with_language("sh"))
+                    .list(List::builder().append("Done").unordered());
+            })
+            .append("Run it")
+            .loose()
+            .ordered();
+
+        assert_eq!(
+            list.render(),
+            "1. Install the crate:\n\n   \
+            ```sh\n   \
+            cargo add markdown-builder\n   \
+            ```\n\n   \
+            - Done\n\n\
+            2. Run it\n"
+        );
+
+        let tight = List::builder()
+            .item(|item| {
+                item.add("let a;".to_code_block_with_language("rust"))
+                    .paragraph("Done.");
+            })
+            .unordered();
+        assert_eq!(tight.render(), "- ```rust\n  let a;\n  ```\n\n  Done.\n");
+    }
+
+    #[test]
+    fn test_nested_tasks() {
+        let list = List::builder()
+            .task(
+                "Release",
+                false,
+                List::builder()
+                    .checkbox("Bump version", true)
+                    .task(
+                        "Publish",
+                        false,
+                        List::builder().checkbox("crates.io", false).unordered(),
+                    )
+                    .unordered(),
+            )
+            .ordered();
+
+        assert_eq!(
+            list.render(),
+            "1. [ ] Release\n   \
+            - [x] Bump version\n   \
+            - [ ] Publish\n     \
+            - [ ] crates.io\n"
+        );
     }
 }
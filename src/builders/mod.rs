@@ -3,6 +3,7 @@
 //! The API provided inside this module can be used to declare Markdown files in
 //! an imperative style.
 
+pub mod diagram;
 pub mod image;
 pub mod link;
 pub mod list;
@@ -0,0 +1,72 @@
+//! Contains the markdown flavors documents can be rendered for.
+//!
+//! Most elements render the same in every flavor. Elements using syntax that
+//! is not part of CommonMark, such as
+//! [DefinitionList](../types/definition_list/struct.DefinitionList.html), use
+//! their native syntax when the flavor supports it and fall back to an
+//! equivalent otherwise.
+
+use crate::types::math::MathSyntax;
+
+/// A markdown dialect.
+///
+/// Set it using
+/// [Markdown::set_flavor](../types/markdown/struct.Markdown.html#method.set_flavor).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Flavor {
+    /// Plain [CommonMark](https://commonmark.org).
+    #[default]
+    CommonMark,
+    /// GitHub Flavored Markdown.
+    GitHub,
+    /// GitLab Flavored Markdown.
+    GitLab,
+    /// Pandoc's markdown.
+    Pandoc,
+    /// PHP Markdown Extra.
+    MarkdownExtra,
+}
+
+impl Flavor {
+    /// Returns whether the flavor is the default, CommonMark.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns whether the flavor supports `Term\n: Definition` lists.
+    pub fn supports_definition_lists(self) -> bool {
+        matches!(self, Self::Pandoc | Self::MarkdownExtra)
+    }
+
+    /// Returns whether the flavor supports `{width=50%}` style attributes on
+    /// images.
+    pub fn supports_image_attributes(self) -> bool {
+        self == Self::Pandoc
+    }
+
+    /// Returns whether the flavor links bare URLs such as `www.example.com`.
+    pub fn supports_extended_autolinks(self) -> bool {
+        matches!(self, Self::GitHub | Self::GitLab)
+    }
+
+    /// Returns the math syntax of the flavor, or `None` if it does not render
+    /// math.
+    pub fn math_syntax(self) -> Option<MathSyntax> {
+        match self {
+            Self::GitHub | Self::GitLab => Some(MathSyntax::Fenced),
+            Self::Pandoc => Some(MathSyntax::Dollars),
+            Self::CommonMark | Self::MarkdownExtra => None,
+        }
+    }
+
+    /// Returns whether the flavor renders math, in which case dollar signs in
+    /// paragraphs, headers and list items are escaped.
+    pub fn supports_math(self) -> bool {
+        self.math_syntax().is_some()
+    }
+}
@@ -0,0 +1,9 @@
+//! Contains helpers for elements rendered as HTML.
+
+/// Escapes the characters that have a special meaning in HTML.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
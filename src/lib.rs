@@ -5,20 +5,62 @@
 
 #![forbid(unsafe_code)]
 
+// Allows the derive macro, which refers to `::markdown_builder`, to be used
+// inside of this crate.
+extern crate self as markdown_builder;
+
 pub mod builders;
+pub mod flavor;
+mod html;
+pub mod lint;
+mod macros;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "serde")]
+mod serializer;
+pub mod terminal;
 pub mod traits;
 pub mod transforms;
 pub mod types;
+pub mod visit;
 pub use crate::{
-    builders::{image::ImageBuilder, link::LinkBuilder, list::ListBuilder},
-    traits::{AsFooter, MarkdownElement},
+    builders::{
+        diagram::{FlowDirection, FlowchartBuilder, NodeShape, SequenceBuilder},
+        image::ImageBuilder,
+        link::LinkBuilder,
+        list::ListBuilder,
+    },
+    flavor::Flavor,
+    lint::{Diagnostic, Linter, Rule},
+    terminal::TerminalRenderer,
+    traits::{AsFooter, MarkdownElement, MarkdownValue, ToMarkdown},
     transforms::{BlockQuote, Bold, CodeBlock, Inline, Italic, Strikethrough},
     types::{
-        header::{Header, HeaderLevel},
+        autolink::Autolink,
+        badge::{Badge, BadgeRow, BadgeStyle},
+        checkbox::{Checkbox, Task},
+        definition_list::{DefinitionFallback, DefinitionItem, DefinitionList},
+        details::Details,
+        diagram::Diagram,
+        header::{Header, HeaderLevel, HeaderLevelError, HeaderOverflow},
         image::Image,
+        include::Include,
         link::Link,
-        list::{List, ListItem, ListVariant},
+        list::{List, ListDelimiter, ListItem, ListVariant, Progress},
         markdown::Markdown,
+        math::{InlineMath, MathBlock, MathSyntax},
+        node::{Children, Node, Nodes},
         paragraph::Paragraph,
+        quote::Quote,
+        section::Section,
+        table::{Alignment, Table},
     },
+    visit::{Visitor, VisitorMut},
 };
+
+#[cfg(feature = "derive")]
+pub use markdown_builder_derive::ToMarkdown;
+#[cfg(feature = "serde")]
+pub use serializer::{to_markdown, SerializeError};
+#[cfg(feature = "csv")]
+pub use types::csv::{CsvError, CsvOptions};
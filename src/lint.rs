@@ -0,0 +1,810 @@
+//! Contains a linter checking documents for common markdownlint rules.
+//!
+//! The [Linter](struct.Linter.html) checks either a
+//! [Markdown](../types/markdown/struct.Markdown.html) model, reporting the
+//! index of the offending element, or raw markdown text, reporting the line.
+//! Some rules can be fixed automatically, see
+//! [Rule::is_fixable](enum.Rule.html#method.is_fixable).
+
+use crate::{
+    types::{
+        header::Header,
+        markdown::Markdown,
+        node::{Node, Nodes},
+    },
+    visit::VisitorMut,
+};
+use std::{fmt, slice};
+
+/// A lint rule, named after its markdownlint counterpart.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Rule {
+    /// Header levels should only increment by one level at a time (MD001).
+    HeadingIncrement,
+    /// Multiple headers with the same text (MD024).
+    DuplicateHeading,
+    /// Multiple top-level headers in the same document (MD025).
+    MultipleTopLevelHeadings,
+    /// Links without a destination or text (MD042).
+    EmptyLink,
+    /// Images should have alternate text (MD045).
+    ImageAltText,
+    /// Lines longer than the configured length (MD013).
+    LineLength,
+    /// Trailing spaces, except for a hard line break of two spaces (MD009).
+    TrailingSpaces,
+    /// URLs used without angle brackets (MD034).
+    BareUrl,
+}
+
+impl Rule {
+    /// All rules, in the order they are checked.
+    pub const ALL: [Rule; 8] = [
+        Rule::HeadingIncrement,
+        Rule::DuplicateHeading,
+        Rule::MultipleTopLevelHeadings,
+        Rule::EmptyLink,
+        Rule::ImageAltText,
+        Rule::LineLength,
+        Rule::TrailingSpaces,
+        Rule::BareUrl,
+    ];
+
+    /// Returns the markdownlint code of the rule, e.g. `MD001`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::HeadingIncrement => "MD001",
+            Self::LineLength => "MD013",
+            Self::TrailingSpaces => "MD009",
+            Self::DuplicateHeading => "MD024",
+            Self::MultipleTopLevelHeadings => "MD025",
+            Self::BareUrl => "MD034",
+            Self::EmptyLink => "MD042",
+            Self::ImageAltText => "MD045",
+        }
+    }
+
+    /// Returns whether violations of the rule can be fixed using
+    /// [Linter::fix](struct.Linter.html#method.fix) or
+    /// [Linter::fix_text](struct.Linter.html#method.fix_text).
+    ///
+    /// Trailing spaces can only be fixed in raw text.
+    pub fn is_fixable(&self) -> bool {
+        matches!(
+            self,
+            Self::HeadingIncrement | Self::TrailingSpaces | Self::BareUrl
+        )
+    }
+}
+
+/// A violation of a [Rule](enum.Rule.html).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// The violated rule.
+    pub rule: Rule,
+    /// A description of the violation.
+    pub message: String,
+    /// The index of the top-level element containing the violation, when
+    /// checking a document.
+    pub element: Option<usize>,
+    /// The 1-based line of the violation. When checking a document, the line
+    /// is relative to the rendered element.
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rule.code())?;
+        match (self.element, self.line) {
+            (Some(element), Some(line)) => write!(f, " element {}, line {}", element, line)?,
+            (Some(element), None) => write!(f, " element {}", element)?,
+            (None, Some(line)) => write!(f, " line {}", line)?,
+            (None, None) => {}
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Checks documents for common markdownlint rules.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Linter {
+    rules: Vec<Rule>,
+    line_length: usize,
+}
+
+impl Default for Linter {
+    /// Returns a linter checking all rules, with a line length of 80.
+    fn default() -> Self {
+        Self {
+            rules: Rule::ALL.to_vec(),
+            line_length: 80,
+        }
+    }
+}
+
+impl Linter {
+    /// Creates a new default `Linter`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum line length.
+    pub fn line_length(mut self, line_length: usize) -> Self {
+        self.line_length = line_length;
+        self
+    }
+
+    /// Disables the given rule.
+    pub fn disable(mut self, rule: Rule) -> Self {
+        self.rules.retain(|enabled| *enabled != rule);
+        self
+    }
+
+    fn is_enabled(&self, rule: Rule) -> bool {
+        self.rules.contains(&rule)
+    }
+
+    /// Checks a document, ordered by element and line.
+    ///
+    /// Line based rules are checked against the rendered elements, so the
+    /// reported lines are relative to the element.
+    pub fn check(&self, doc: &Markdown) -> Vec<Diagnostic> {
+        let mut headings = Vec::new();
+        let mut diagnostics = Vec::new();
+
+        for (index, element) in doc.elements.iter().enumerate() {
+            for node in Nodes::new(slice::from_ref(element)) {
+                match node {
+                    Node::Header(header) => headings.push((index, header)),
+                    Node::Section(section) => headings.push((index, &section.header)),
+                    Node::Link(link) if self.is_enabled(Rule::EmptyLink) => {
+                        if let Some(message) = empty_link(&link.text, &link.url) {
+                            diagnostics.push(Diagnostic {
+                                rule: Rule::EmptyLink,
+                                message,
+                                element: Some(index),
+                                line: None,
+                            });
+                        }
+                    }
+                    Node::Image(image)
+                        if self.is_enabled(Rule::ImageAltText) && image.text.trim().is_empty() =>
+                    {
+                        diagnostics.push(Diagnostic {
+                            rule: Rule::ImageAltText,
+                            message: format!("Image {} has no alternate text", image.url),
+                            element: Some(index),
+                            line: None,
+                        });
+                    }
+                    Node::Text(text) if self.is_enabled(Rule::BareUrl) => {
+                        for (_, url) in bare_urls(text) {
+                            diagnostics.push(bare_url(url, Some(index), None));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let rendered = element.render_with(doc.flavor);
+            for mut diagnostic in self.check_lines(&rendered) {
+                diagnostic.element = Some(index);
+                diagnostics.push(diagnostic);
+            }
+        }
+
+        diagnostics.extend(
+            self.check_headings(headings.into_iter().map(|(index, header)| {
+                (
+                    (Some(index), None),
+                    header.level.value(),
+                    header.text.as_str(),
+                )
+            })),
+        );
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.element, diagnostic.line));
+        diagnostics
+    }
+
+    /// Checks raw markdown text, ordered by line.
+    ///
+    /// Only ATX headers (`# Title`) and inline links and images are
+    /// recognized.
+    pub fn check_text(&self, text: &str) -> Vec<Diagnostic> {
+        let mut headings = Vec::new();
+        let mut diagnostics = self.check_lines(text);
+
+        for (line_idx, line, is_code) in lines(text) {
+            if is_code {
+                continue;
+            }
+            let line_no = Some(line_idx + 1);
+
+            if let Some((level, heading)) = atx_heading(line) {
+                headings.push(((None, line_no), level, heading));
+            }
+
+            if self.is_enabled(Rule::BareUrl) {
+                for (_, url) in bare_urls(line) {
+                    diagnostics.push(bare_url(url, None, line_no));
+                }
+            }
+
+            for (is_image, text, url) in inline_links(line) {
+                if is_image {
+                    if self.is_enabled(Rule::ImageAltText) && text.trim().is_empty() {
+                        diagnostics.push(Diagnostic {
+                            rule: Rule::ImageAltText,
+                            message: format!("Image {} has no alternate text", url),
+                            element: None,
+                            line: line_no,
+                        });
+                    }
+                } else if self.is_enabled(Rule::EmptyLink) {
+                    if let Some(message) = empty_link(text, url) {
+                        diagnostics.push(Diagnostic {
+                            rule: Rule::EmptyLink,
+                            message,
+                            element: None,
+                            line: line_no,
+                        });
+                    }
+                }
+            }
+        }
+
+        diagnostics.extend(self.check_headings(headings.into_iter()));
+        diagnostics.sort_by_key(|diagnostic| diagnostic.line);
+        diagnostics
+    }
+
+    /// Fixes the fixable rules of the document, returning the number of fixes.
+    ///
+    /// Header levels skipping a level are lowered and bare URLs inside of text
+    /// are wrapped in angle brackets.
+    pub fn fix(&self, doc: &mut Markdown) -> usize {
+        let mut fixer = Fixer {
+            linter: self,
+            previous_level: None,
+            fixes: 0,
+        };
+        doc.walk_mut(&mut fixer);
+        fixer.fixes
+    }
+
+    /// Fixes the fixable rules of raw markdown text.
+    ///
+    /// Trailing spaces are removed and bare URLs are wrapped in angle
+    /// brackets. Headers are left as-is.
+    pub fn fix_text(&self, text: &str) -> String {
+        map_lines(text, |line, is_code| {
+            let mut line = line.to_string();
+            if !is_code && self.is_enabled(Rule::BareUrl) {
+                line = wrap_bare_urls(&line);
+            }
+            if self.is_enabled(Rule::TrailingSpaces) && has_trailing_spaces(&line) {
+                line.truncate(line.trim_end().len());
+            }
+            line
+        })
+    }
+
+    /// Checks the rules applying to every line of rendered markdown.
+    fn check_lines(&self, text: &str) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for (line_idx, line, is_code) in lines(text) {
+            let line_no = Some(line_idx + 1);
+            let length = line.chars().count();
+
+            if self.is_enabled(Rule::LineLength)
+                && !is_code
+                && length > self.line_length
+                && line.chars().skip(self.line_length).any(char::is_whitespace)
+            {
+                diagnostics.push(Diagnostic {
+                    rule: Rule::LineLength,
+                    message: format!(
+                        "Line is {} characters long, the maximum is {}",
+                        length, self.line_length
+                    ),
+                    element: None,
+                    line: line_no,
+                });
+            }
+
+            if self.is_enabled(Rule::TrailingSpaces) && has_trailing_spaces(line) {
+                diagnostics.push(Diagnostic {
+                    rule: Rule::TrailingSpaces,
+                    message: "Line ends with trailing spaces".to_string(),
+                    element: None,
+                    line: line_no,
+                });
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Checks the header rules for the headers in document order.
+    fn check_headings<'a>(
+        &self,
+        headings: impl Iterator<Item = ((Option<usize>, Option<usize>), usize, &'a str)>,
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut previous_level = None;
+        let mut seen: Vec<&str> = Vec::new();
+        let mut has_top_level = false;
+
+        for ((element, line), level, text) in headings {
+            let text = text.trim();
+            let mut report = |rule: Rule, message: String| {
+                if self.is_enabled(rule) {
+                    diagnostics.push(Diagnostic {
+                        rule,
+                        message,
+                        element,
+                        line,
+                    });
+                }
+            };
+
+            if let Some(previous) = previous_level {
+                if level > previous + 1 {
+                    report(
+                        Rule::HeadingIncrement,
+                        format!(
+                            "Header {:?} skips from level {} to {}",
+                            text, previous, level
+                        ),
+                    );
+                }
+            }
+            if seen.contains(&text) {
+                report(
+                    Rule::DuplicateHeading,
+                    format!("Header {:?} is used more than once", text),
+                );
+            }
+            if level == 1 {
+                if has_top_level {
+                    report(
+                        Rule::MultipleTopLevelHeadings,
+                        format!("Header {:?} is not the only top-level header", text),
+                    );
+                }
+                has_top_level = true;
+            }
+
+            previous_level = Some(level);
+            seen.push(text);
+        }
+
+        diagnostics
+    }
+}
+
+impl Markdown {
+    /// Checks the document using the default
+    /// [Linter](../../lint/struct.Linter.html).
+    pub fn lint(&self) -> Vec<Diagnostic> {
+        Linter::default().check(self)
+    }
+}
+
+/// Applies the fixes of [Linter::fix](struct.Linter.html#method.fix).
+struct Fixer<'a> {
+    linter: &'a Linter,
+    previous_level: Option<usize>,
+    fixes: usize,
+}
+
+impl VisitorMut for Fixer<'_> {
+    fn visit_header_mut(&mut self, header: &mut Header) {
+        let level = header.level.value();
+        match self.previous_level {
+            Some(previous)
+                if level > previous + 1 && self.linter.is_enabled(Rule::HeadingIncrement) =>
+            {
+                header.level = (previous + 1).into();
+                self.fixes += 1;
+            }
+            _ => {}
+        }
+        self.previous_level = Some(header.level.value());
+    }
+
+    fn visit_text_mut(&mut self, text: &mut String) {
+        if !self.linter.is_enabled(Rule::BareUrl) {
+            return;
+        }
+
+        let count = bare_urls(text).count();
+        if count > 0 {
+            *text = map_lines(text, |line, is_code| {
+                if is_code {
+                    line.to_string()
+                } else {
+                    wrap_bare_urls(line)
+                }
+            });
+            self.fixes += count;
+        }
+    }
+}
+
+/// Returns the lines of the text along with their index and whether they are
+/// part of a fenced code block, including the fences themselves.
+///
+/// A fence is closed by a fence of the same character that is at least as
+/// long as the opening one, as in CommonMark.
+fn lines(text: &str) -> impl Iterator<Item = (usize, &str, bool)> {
+    let mut fence: Option<(char, usize)> = None;
+
+    text.lines().enumerate().map(move |(idx, line)| {
+        let trimmed = line.trim_start();
+        // The info string of a backtick fence cannot contain backticks, e.g.
+        // ```` ```code``` ```` is an inline code span.
+        let marker = ['`', '~'].into_iter().find_map(|c| {
+            let len = trimmed.chars().take_while(|t| *t == c).count();
+            (len >= 3 && !(c == '`' && trimmed[len..].contains('`'))).then_some((c, len))
+        });
+
+        match (fence, marker) {
+            (None, Some(marker)) => {
+                fence = Some(marker);
+                (idx, line, true)
+            }
+            (Some((open, open_len)), Some((c, len)))
+                if open == c && len >= open_len && trimmed[len..].trim().is_empty() =>
+            {
+                fence = None;
+                (idx, line, true)
+            }
+            (Some(_), _) => (idx, line, true),
+            (None, None) => (idx, line, false),
+        }
+    })
+}
+
+/// Replaces every line of the text, keeping a final line break.
+fn map_lines(text: &str, mut map: impl FnMut(&str, bool) -> String) -> String {
+    let mut output = lines(text)
+        .map(|(_, line, is_code)| map(line, is_code))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if text.ends_with('\n') {
+        output.push('\n');
+    }
+    output
+}
+
+/// Returns whether the line ends with whitespace other than a hard line break
+/// of exactly two spaces.
+fn has_trailing_spaces(line: &str) -> bool {
+    let trimmed = line.trim_end();
+    let trailing = &line[trimmed.len()..];
+
+    !trailing.is_empty() && (trimmed.is_empty() || trailing != "  ")
+}
+
+/// Returns the bare URLs of the text outside of code blocks and code spans,
+/// along with the index of the line they are in.
+fn bare_urls(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    lines(text)
+        .filter(|(_, _, is_code)| !is_code)
+        .flat_map(|(idx, line, _)| {
+            words(line)
+                .into_iter()
+                .filter(|(_, is_code)| !is_code)
+                .filter_map(|(word, _)| bare_url_of(word))
+                .map(move |url| (idx, url))
+        })
+}
+
+/// Returns the words of the line along with whether they are part of a code
+/// span, e.g. `` `a b` ``.
+fn words(line: &str) -> Vec<(&str, bool)> {
+    let mut spans = Vec::new();
+    let mut index = 0;
+    while let Some(start) = line[index..].find('`').map(|start| index + start) {
+        let run = line[start..].chars().take_while(|c| *c == '`').count();
+        let mut search = start + run;
+        index = search;
+        while let Some(end) = line[search..].find('`').map(|end| search + end) {
+            let closing = line[end..].chars().take_while(|c| *c == '`').count();
+            if closing == run {
+                spans.push(start..end + closing);
+                index = end + closing;
+                break;
+            }
+            search = end + closing;
+        }
+    }
+
+    let mut offset = 0;
+    line.split(' ')
+        .map(|word| {
+            let range = offset..offset + word.len();
+            offset = range.end + 1;
+            let is_code = spans
+                .iter()
+                .any(|span| span.start < range.end && range.start < span.end);
+            (word, is_code)
+        })
+        .collect()
+}
+
+/// Returns the URL if the word is a bare URL, without trailing punctuation.
+///
+/// Like GFM extended autolinks, a trailing `)` is only removed if it is not
+/// balanced by a `(` within the URL, e.g. `https://example.com/a_(b)`.
+fn bare_url_of(word: &str) -> Option<&str> {
+    if !(word.starts_with("http://") || word.starts_with("https://")) || word.contains('`') {
+        return None;
+    }
+
+    let mut url = word;
+    loop {
+        if let Some(trimmed) = url.strip_suffix(|c: char| ".,:;!?".contains(c)) {
+            url = trimmed;
+        } else if url.ends_with(')') && url.matches(')').count() > url.matches('(').count() {
+            url = &url[..url.len() - 1];
+        } else {
+            return Some(url);
+        }
+    }
+}
+
+/// Wraps every bare URL of the line outside of code spans in angle brackets.
+fn wrap_bare_urls(line: &str) -> String {
+    words(line)
+        .into_iter()
+        .map(
+            |(word, is_code)| match bare_url_of(word).filter(|_| !is_code) {
+                Some(url) => format!("<{}>{}", url, &word[url.len()..]),
+                None => word.to_string(),
+            },
+        )
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bare_url(url: &str, element: Option<usize>, line: Option<usize>) -> Diagnostic {
+    Diagnostic {
+        rule: Rule::BareUrl,
+        message: format!("Bare URL {}, use <{}> instead", url, url),
+        element,
+        line,
+    }
+}
+
+/// Returns the message if the link has no destination or text.
+fn empty_link(text: &str, url: &str) -> Option<String> {
+    let url = url.trim();
+    if url.is_empty() || url == "#" {
+        Some(format!("Link {:?} has no destination", text))
+    } else if text.trim().is_empty() {
+        Some(format!("Link to {} has no text", url))
+    } else {
+        None
+    }
+}
+
+/// Returns the level and text if the line is an ATX header.
+fn atx_heading(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    let rest = &trimmed[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+        return None;
+    }
+
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+/// Returns the inline links of the line as `(is_image, text, url)`.
+///
+/// Nested brackets and reference links are not recognized.
+fn inline_links(line: &str) -> Vec<(bool, &str, &str)> {
+    let mut links = Vec::new();
+    let mut rest = line;
+
+    while let Some(open) = rest.find('[') {
+        let is_image = rest[..open].ends_with('!');
+        let after = &rest[open + 1..];
+        let Some(close) = after.find("](") else {
+            break;
+        };
+        let text = &after[..close];
+        let destination = &after[close + 2..];
+        let Some(end) = destination.find(')') else {
+            break;
+        };
+
+        if !text.contains('[') {
+            let url = destination[..end].split(' ').next().unwrap_or_default();
+            links.push((is_image, text, url));
+        }
+        rest = &destination[end + 1..];
+    }
+
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ImageBuilder, Link, Paragraph};
+
+    fn rules(diagnostics: &[Diagnostic]) -> Vec<Rule> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.rule)
+            .collect()
+    }
+
+    #[test]
+    fn test_heading_rules() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").h3("Usage").h1("Title");
+
+        let diagnostics = doc.lint();
+        assert_eq!(
+            rules(&diagnostics),
+            vec![
+                Rule::HeadingIncrement,
+                Rule::DuplicateHeading,
+                Rule::MultipleTopLevelHeadings
+            ]
+        );
+        assert_eq!(diagnostics[0].element, Some(1));
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "MD001 element 1: Header \"Usage\" skips from level 1 to 3"
+        );
+        assert_eq!(diagnostics[2].element, Some(2));
+    }
+
+    #[test]
+    fn test_link_and_image_rules() {
+        let mut doc = Markdown::new();
+        doc.link(Link::from("#", "Docs", false, false))
+            .image(ImageBuilder::new().url("logo.png").build())
+            .paragraph("See https://example.com.");
+
+        let diagnostics = Linter::new().check(&doc);
+        assert_eq!(
+            rules(&diagnostics),
+            vec![Rule::EmptyLink, Rule::ImageAltText, Rule::BareUrl]
+        );
+        assert_eq!(diagnostics[2].element, Some(2));
+        assert!(diagnostics[2].message.contains("<https://example.com>"));
+    }
+
+    #[test]
+    fn test_line_rules() {
+        let mut doc = Markdown::new();
+        doc.add(format!("{} end", "a".repeat(80)))
+            .add("hard  \nbreak \n```\nlong code line\n```")
+            .add("x".repeat(100));
+
+        let diagnostics = Linter::new().line_length(20).check(&doc);
+        assert_eq!(
+            rules(&diagnostics),
+            vec![Rule::LineLength, Rule::TrailingSpaces]
+        );
+        assert_eq!(
+            (diagnostics[1].element, diagnostics[1].line),
+            (Some(1), Some(2))
+        );
+
+        assert!(Linter::new()
+            .disable(Rule::LineLength)
+            .disable(Rule::TrailingSpaces)
+            .check(&doc)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_check_text() {
+        let text = "# Title\n\n### Usage \n\n[](https://example.com) ![](a.png)\n\n\
+                    ```\nhttps://example.com\n```\n\nhttps://example.com\n";
+
+        let diagnostics = Linter::new().check_text(text);
+        assert_eq!(
+            rules(&diagnostics),
+            vec![
+                Rule::TrailingSpaces,
+                Rule::HeadingIncrement,
+                Rule::EmptyLink,
+                Rule::ImageAltText,
+                Rule::BareUrl
+            ]
+        );
+        assert_eq!(diagnostics[1].line, Some(3));
+        assert_eq!(diagnostics[4].line, Some(11));
+    }
+
+    #[test]
+    fn test_fix() {
+        let mut doc = Markdown::new();
+        doc.h1("Title")
+            .h3("Usage")
+            .add(Paragraph::from("Visit https://example.com, please"));
+
+        assert_eq!(Linter::new().fix(&mut doc), 2);
+        assert!(doc.lint().is_empty());
+        assert_eq!(
+            doc.render(),
+            "# Title\n\n## Usage\n\nVisit <https://example.com>, please\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_text() {
+        let text = "Visit https://example.com \nhard  \n```\nhttps://example.com \n```\n";
+
+        assert_eq!(
+            Linter::new().fix_text(text),
+            "Visit <https://example.com>\nhard  \n```\nhttps://example.com\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_bare_url_parentheses() {
+        assert_eq!(
+            bare_url_of("https://example.com/a_(b)"),
+            Some("https://example.com/a_(b)")
+        );
+        assert_eq!(
+            bare_url_of("https://example.com/a_(b))."),
+            Some("https://example.com/a_(b)")
+        );
+        assert_eq!(
+            Linter::new().fix_text("(see https://example.com/a_(b))\n"),
+            "(see <https://example.com/a_(b)>)\n"
+        );
+    }
+
+    #[test]
+    fn test_bare_url_in_code_span() {
+        let text = "Run `curl https://example.com` or `` a ` https://example.com ``\n";
+
+        assert!(Linter::new().check_text(text).is_empty());
+        assert_eq!(Linter::new().fix_text(text), text);
+    }
+
+    #[test]
+    fn test_long_fences() {
+        let text = "````\n```\nhttps://example.com\n```\n````\n\nhttps://example.com\n";
+
+        let diagnostics = Linter::new().check_text(text);
+        assert_eq!(rules(&diagnostics), vec![Rule::BareUrl]);
+        assert_eq!(diagnostics[0].line, Some(7));
+    }
+
+    #[test]
+    fn test_inline_code_is_not_a_fence() {
+        let text = "```let a;``` is code\n\nhttps://example.com\n";
+
+        let diagnostics = Linter::new().check_text(text);
+        assert_eq!(rules(&diagnostics), vec![Rule::BareUrl]);
+        assert_eq!(diagnostics[0].line, Some(3));
+    }
+
+    #[test]
+    fn test_wrapped_paragraph_is_clean() {
+        let mut doc = Markdown::new();
+        doc.paragraph(
+            "Markdown Builder is a Rustlang crate by Erb3, which lets you create markdown \
+            documents. It now supports word wrapping!",
+        );
+
+        assert!(doc.lint().is_empty());
+    }
+}
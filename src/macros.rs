@@ -0,0 +1,247 @@
+//! Contains the [markdown](../macro.markdown.html) macro.
+
+/// Builds a [Markdown](types/markdown/struct.Markdown.html) document using a
+/// declarative syntax.
+///
+/// Each statement expands to the matching builder call, so the document stays
+/// type-checked. Statements are separated by semicolons. Text literals are
+/// passed to `format!`, so they can capture variables (`"Hello {name}"`) or be
+/// followed by format arguments (`"{} items", items.len()`).
+///
+/// # Statements
+///
+/// - `h1 "text"` to `h6 "text"`: A header.
+/// - `p "text"`: A paragraph.
+/// - `list ["a", "b"]`: An unordered list with at least one item. The items
+///   are either all text literals or any markdown elements, e.g.
+///   `list [name.clone(), Checkbox::from("done", true)]`, which are not
+///   formatted.
+/// - `ordered ["a", "b"]`: An ordered list, taking the same items.
+/// - `code rust "fn main() {}"`: A code block, the language is optional. The
+///   code is any expression and is not formatted.
+/// - `quote "text"`: A block quote containing a paragraph.
+/// - `link "text" => "url"`: A link.
+/// - `image "alt" => "url"`: An image.
+/// - `section "title" { ... }`: A section containing the nested statements.
+///   The title can capture variables, but not take format arguments. Headers
+///   cannot be used inside of sections, their levels are derived from
+///   the nesting.
+/// - `add expr`: Any markdown element.
+///
+/// # Example
+///
+/// ```
+/// use markdown_builder::markdown;
+///
+/// let name = "World";
+/// let doc = markdown! {
+///     h1 "Hello {name}";
+///     p "Some text.";
+///     list ["a", "b"];
+///     code rust "fn main() {}";
+/// };
+///
+/// assert_eq!(
+///     doc.render(),
+///     "# Hello World\n\nSome text.\n\n- a\n- b\n\n```rust\nfn main() {}\n```\n"
+/// );
+/// ```
+///
+/// Lists need at least one item:
+///
+/// ```compile_fail
+/// use markdown_builder::markdown;
+///
+/// let doc = markdown! {
+///     list [];
+/// };
+/// ```
+#[macro_export]
+macro_rules! markdown {
+    (@add $target:ident;) => {};
+    (@add $target:ident; h1 $fmt:literal $(, $arg:expr)* $(; $($rest:tt)*)?) => {
+        $target.h1(format!($fmt $(, $arg)*));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; h2 $fmt:literal $(, $arg:expr)* $(; $($rest:tt)*)?) => {
+        $target.h2(format!($fmt $(, $arg)*));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; h3 $fmt:literal $(, $arg:expr)* $(; $($rest:tt)*)?) => {
+        $target.h3(format!($fmt $(, $arg)*));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; h4 $fmt:literal $(, $arg:expr)* $(; $($rest:tt)*)?) => {
+        $target.h4(format!($fmt $(, $arg)*));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; h5 $fmt:literal $(, $arg:expr)* $(; $($rest:tt)*)?) => {
+        $target.h5(format!($fmt $(, $arg)*));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; h6 $fmt:literal $(, $arg:expr)* $(; $($rest:tt)*)?) => {
+        $target.h6(format!($fmt $(, $arg)*));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; p $fmt:literal $(, $arg:expr)* $(; $($rest:tt)*)?) => {
+        $target.paragraph(format!($fmt $(, $arg)*));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; list [$($item:literal),+ $(,)?] $(; $($rest:tt)*)?) => {
+        $target.list($crate::ListBuilder::new()$(.append(format!($item)))+.unordered());
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; list [$($item:expr),+ $(,)?] $(; $($rest:tt)*)?) => {
+        $target.list($crate::ListBuilder::new()$(.append($item))+.unordered());
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; ordered [$($item:literal),+ $(,)?] $(; $($rest:tt)*)?) => {
+        $target.list($crate::ListBuilder::new()$(.append(format!($item)))+.ordered());
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; ordered [$($item:expr),+ $(,)?] $(; $($rest:tt)*)?) => {
+        $target.list($crate::ListBuilder::new()$(.append($item))+.ordered());
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; code $language:ident $code:expr $(; $($rest:tt)*)?) => {
+        $target.add(format!(
+            "{}\n",
+            $crate::CodeBlock::to_code_block_with_language(&$code, stringify!($language))
+        ));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; code $code:expr $(; $($rest:tt)*)?) => {
+        $target.add(format!("{}\n", $crate::CodeBlock::to_code_block(&$code)));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; quote $fmt:literal $(, $arg:expr)* $(; $($rest:tt)*)?) => {
+        $target.quote($crate::Quote::with(vec![$crate::Paragraph::from(format!(
+            $fmt $(, $arg)*
+        ))]));
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; link $text:literal => $url:expr $(; $($rest:tt)*)?) => {
+        $target.link($crate::LinkBuilder::new().text(format!($text)).url($url).build());
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; image $text:literal => $url:expr $(; $($rest:tt)*)?) => {
+        $target.image($crate::ImageBuilder::new().text(format!($text)).url($url).build());
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; section $title:literal { $($body:tt)* } $(; $($rest:tt)*)?) => {
+        $target.section(format!($title), |#[allow(unused_variables)] section| {
+            $crate::markdown!(@add section; $($body)*);
+        });
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    (@add $target:ident; add $element:expr $(; $($rest:tt)*)?) => {
+        $target.add($element);
+        $crate::markdown!(@add $target; $($($rest)*)?);
+    };
+    ($($body:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut doc = $crate::Markdown::new();
+        $crate::markdown!(@add doc; $($body)*);
+        doc
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ListBuilder, Markdown};
+
+    #[test]
+    fn test_empty_document() {
+        assert_eq!(markdown! {}.render(), "");
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let name = "World";
+        let items = [1, 2, 3];
+        let doc = markdown! {
+            h1 "Hello {name}";
+            p "{} items", items.len();
+        };
+
+        assert_eq!(doc.render(), "# Hello World\n\n3 items\n");
+    }
+
+    #[test]
+    fn test_matches_builder_calls() {
+        let version = "1.0";
+        let doc = markdown! {
+            h2 "Install";
+            ordered ["Download {version}", "Run it"];
+            code "cargo run";
+            quote "Enjoy!";
+            link "Docs" => "https://docs.rs";
+            image "Logo" => "logo.png"
+        };
+
+        let mut expected = Markdown::new();
+        expected
+            .h2("Install")
+            .list(
+                ListBuilder::new()
+                    .append("Download 1.0")
+                    .append("Run it")
+                    .ordered(),
+            )
+            .add("```\ncargo run\n```\n")
+            .quote(crate::Quote::with(vec![crate::Paragraph::from("Enjoy!")]))
+            .link(
+                crate::LinkBuilder::new()
+                    .text("Docs")
+                    .url("https://docs.rs")
+                    .build(),
+            )
+            .image(
+                crate::ImageBuilder::new()
+                    .text("Logo")
+                    .url("logo.png")
+                    .build(),
+            );
+
+        assert_eq!(doc.render(), expected.render());
+    }
+
+    #[test]
+    fn test_list_expressions() {
+        let tool = String::from("cargo");
+        let doc = markdown! {
+            list [tool.clone(), crate::Checkbox::from("Install", true)];
+            ordered [format!("Run {}", tool)];
+        };
+
+        assert_eq!(doc.render(), "- cargo\n- [x] Install\n\n1. Run cargo\n");
+    }
+
+    #[test]
+    fn test_code_is_not_formatted() {
+        let doc = markdown! {
+            code rust "fn main() {}";
+        };
+
+        assert_eq!(doc.render(), "```rust\nfn main() {}\n```\n");
+    }
+
+    #[test]
+    fn test_sections() {
+        let doc = markdown! {
+            section "Install" {
+                p "Pick your platform.";
+                section "Linux" {
+                    list ["apt", "pacman"];
+                }
+            };
+            section "Empty" {};
+            add "Done.\n";
+        };
+
+        assert_eq!(
+            doc.render(),
+            "# Install\n\nPick your platform.\n\n## Linux\n\n- apt\n- pacman\n\n# Empty\n\nDone.\n"
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! Contains the serde representation of markdown nodes.
+//!
+//! Nodes are serialized using an internally tagged representation, e.g.
+//! `{"type": "paragraph", "text": "Hello"}`. Custom elements cannot be
+//! reconstructed, so they are serialized as their pre-rendered markdown and
+//! deserialized as a [Text](../types/node/enum.Node.html#variant.Text) node.
+
+use crate::types::{
+    autolink::Autolink,
+    checkbox::{Checkbox, Task},
+    definition_list::DefinitionList,
+    details::Details,
+    diagram::Diagram,
+    header::Header,
+    image::Image,
+    include::Include,
+    link::Link,
+    list::List,
+    math::{InlineMath, MathBlock},
+    node::Node,
+    paragraph::Paragraph,
+    quote::Quote,
+    section::Section,
+    table::Table,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The borrowed, tagged representation of a node.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum NodeRef<'a> {
+    Header(&'a Header),
+    Paragraph(&'a Paragraph),
+    Link(&'a Link),
+    Image(&'a Image),
+    List(&'a List),
+    Checkbox(&'a Checkbox),
+    Task(&'a Task),
+    Quote(&'a Quote),
+    Include(&'a Include),
+    Section(&'a Section),
+    Table(&'a Table),
+    DefinitionList(&'a DefinitionList),
+    Details(&'a Details),
+    MathBlock(&'a MathBlock),
+    InlineMath(&'a InlineMath),
+    Diagram(&'a Diagram),
+    Autolink(&'a Autolink),
+    Text { text: &'a str },
+    Raw { markdown: String },
+}
+
+/// The owned, tagged representation of a node.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum NodeDef {
+    Header(Header),
+    Paragraph(Paragraph),
+    Link(Link),
+    Image(Image),
+    List(List),
+    Checkbox(Checkbox),
+    Task(Task),
+    Quote(Quote),
+    Include(Include),
+    Section(Section),
+    Table(Table),
+    DefinitionList(DefinitionList),
+    Details(Details),
+    MathBlock(MathBlock),
+    InlineMath(InlineMath),
+    Diagram(Diagram),
+    Autolink(Autolink),
+    Text { text: String },
+    Raw { markdown: String },
+}
+
+impl Serialize for Node {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Node::Header(header) => NodeRef::Header(header),
+            Node::Paragraph(paragraph) => NodeRef::Paragraph(paragraph),
+            Node::Link(link) => NodeRef::Link(link),
+            Node::Image(image) => NodeRef::Image(image),
+            Node::List(list) => NodeRef::List(list),
+            Node::Checkbox(checkbox) => NodeRef::Checkbox(checkbox),
+            Node::Task(task) => NodeRef::Task(task),
+            Node::Quote(quote) => NodeRef::Quote(quote),
+            Node::Include(include) => NodeRef::Include(include),
+            Node::Section(section) => NodeRef::Section(section),
+            Node::Table(table) => NodeRef::Table(table),
+            Node::DefinitionList(list) => NodeRef::DefinitionList(list),
+            Node::Details(details) => NodeRef::Details(details),
+            Node::MathBlock(math) => NodeRef::MathBlock(math),
+            Node::InlineMath(math) => NodeRef::InlineMath(math),
+            Node::Diagram(diagram) => NodeRef::Diagram(diagram),
+            Node::Autolink(link) => NodeRef::Autolink(link),
+            Node::Text(text) => NodeRef::Text { text },
+            Node::Custom(element) => NodeRef::Raw {
+                markdown: element.render(),
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match NodeDef::deserialize(deserializer)? {
+            NodeDef::Header(header) => Node::Header(header),
+            NodeDef::Paragraph(paragraph) => Node::Paragraph(paragraph),
+            NodeDef::Link(link) => Node::Link(link),
+            NodeDef::Image(image) => Node::Image(image),
+            NodeDef::List(list) => Node::List(list),
+            NodeDef::Checkbox(checkbox) => Node::Checkbox(checkbox),
+            NodeDef::Task(task) => Node::Task(task),
+            NodeDef::Quote(quote) => Node::Quote(quote),
+            NodeDef::Include(include) => Node::Include(include),
+            NodeDef::Section(section) => Node::Section(section),
+            NodeDef::Table(table) => Node::Table(table),
+            NodeDef::DefinitionList(list) => Node::DefinitionList(list),
+            NodeDef::Details(details) => Node::Details(details),
+            NodeDef::MathBlock(math) => Node::MathBlock(math),
+            NodeDef::InlineMath(math) => Node::InlineMath(math),
+            NodeDef::Diagram(diagram) => Node::Diagram(diagram),
+            NodeDef::Autolink(link) => Node::Autolink(link),
+            NodeDef::Text { text } | NodeDef::Raw { markdown: text } => Node::Text(text),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Link, LinkBuilder, ListBuilder, Markdown, Node, Paragraph};
+    use serde_json::json;
+
+    #[derive(Clone, Debug)]
+    struct TableOfContents;
+
+    impl std::fmt::Display for TableOfContents {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            writeln!(f, "[[toc]]")
+        }
+    }
+
+    fn document() -> Markdown {
+        let mut doc = Markdown::new();
+        doc.h2("Title")
+            .paragraph("Hello World")
+            .link(
+                LinkBuilder::new()
+                    .url("https://example.com")
+                    .text("Example")
+                    .footer()
+                    .build(),
+            )
+            .list(
+                ListBuilder::new()
+                    .append("Eat")
+                    .checkbox("Sleep", true)
+                    .unordered(),
+            )
+            .add(TableOfContents);
+        doc
+    }
+
+    #[test]
+    fn test_serialize_document() {
+        assert_eq!(
+            serde_json::to_value(document()).unwrap(),
+            json!({
+                "elements": [
+                    { "type": "header", "text": "Title", "level": 2 },
+                    { "type": "paragraph", "text": "Hello World" },
+                    {
+                        "type": "link",
+                        "url": "https://example.com",
+                        "text": "Example",
+                        "footer": true,
+                        "inlined": false
+                    },
+                    {
+                        "type": "list",
+                        "items": [
+                            { "type": "text", "text": "Eat" },
+                            { "type": "checkbox", "checked": "Sleep" }
+                        ],
+                        "variant": "unordered"
+                    },
+                    { "type": "raw", "markdown": "[[toc]]\n" }
+                ],
+                "footers": []
+            })
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_renders_identically() {
+        let doc = document();
+        let json = serde_json::to_string(&doc).unwrap();
+        let parsed: Markdown = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.render(), doc.render());
+    }
+
+    #[test]
+    fn test_roundtrip_keeps_types() {
+        let json = serde_json::to_string(&document()).unwrap();
+        let parsed: Markdown = serde_json::from_str(&json).unwrap();
+
+        assert!(matches!(parsed.elements[0], Node::Header(_)));
+        assert!(matches!(parsed.elements[3], Node::List(_)));
+        assert!(matches!(parsed.elements[4], Node::Text(_)));
+    }
+
+    #[test]
+    fn test_roundtrip_sections() {
+        let mut doc = Markdown::new();
+        doc.section("Install", |s| {
+            s.paragraph("Pick your platform.").section("Linux", |s| {
+                s.paragraph("Use your package manager.");
+            });
+        });
+
+        let json = serde_json::to_value(&doc).unwrap();
+        assert_eq!(json["elements"][0]["type"], "section");
+        assert_eq!(json["elements"][0]["children"][1]["header"]["level"], 2);
+
+        let parsed: Markdown = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.render(), doc.render());
+    }
+
+    #[test]
+    fn test_roundtrip_inline_paragraph() {
+        let mut paragraph = Paragraph::from("See ");
+        paragraph.link(Link::from("https://example.com", "here", false, true));
+        let mut doc = Markdown::new();
+        doc.add(paragraph);
+
+        let json = serde_json::to_value(&doc).unwrap();
+        assert_eq!(json["elements"][0]["children"][1]["type"], "link");
+
+        let parsed: Markdown = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.render(), "See [here](https://example.com)\n");
+    }
+
+    #[test]
+    fn test_invalid_header_level() {
+        let result = serde_json::from_value::<Markdown>(json!({
+            "elements": [{ "type": "header", "text": "Title", "level": 7 }],
+            "footers": []
+        }));
+
+        assert!(result.is_err());
+    }
+}
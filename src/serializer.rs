@@ -0,0 +1,661 @@
+//! Contains a serde serializer rendering arbitrary data as markdown.
+//!
+//! The data is first serialized into a small tree of scalars, sequences and
+//! maps, which is then turned into markdown elements:
+//!
+//! - Sequences of maps or structs become tables, one row per element.
+//! - Maps and structs become `key: value` bullets.
+//! - Other sequences become bullets.
+//! - Nested structures become nested lists.
+//! - Scalars become inline code.
+
+use crate::types::{
+    include::Include, link::escape_text, list::List, markdown::Markdown, node::Node,
+    paragraph::Paragraph, table::Table,
+};
+use serde::{ser, Serialize};
+use std::fmt;
+
+/// Renders any serializable value as a markdown document.
+///
+/// # Errors
+///
+/// Fails if the value cannot be serialized, e.g. if a map key is not a scalar.
+pub fn to_markdown<T>(value: &T) -> Result<Markdown, SerializeError>
+where
+    T: Serialize + ?Sized,
+{
+    let data = value.serialize(Serializer)?;
+    let mut doc = Markdown::new();
+
+    match data {
+        Data::Null => {}
+        Data::Scalar(scalar) => {
+            doc.paragraph(inline_code(&scalar));
+        }
+        data => doc.elements.push(block(data)),
+    }
+
+    Ok(doc)
+}
+
+/// An error that occurred while rendering a value using
+/// [to_markdown](fn.to_markdown.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializeError(String);
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// The serialized shape of a value.
+#[derive(Clone, Debug, PartialEq)]
+enum Data {
+    Null,
+    Scalar(String),
+    Seq(Vec<Data>),
+    Map(Vec<(String, Data)>),
+}
+
+/// Wraps a scalar in inline code, using a longer fence if the scalar contains
+/// backticks.
+fn inline_code(scalar: &str) -> String {
+    if scalar.is_empty() {
+        return String::new();
+    }
+
+    let longest = scalar.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest + 1);
+    if longest == 0 {
+        format!("{}{}{}", fence, scalar, fence)
+    } else {
+        format!("{} {} {}", fence, scalar, fence)
+    }
+}
+
+/// Renders data as a single line, used inside of table cells.
+fn inline(data: &Data) -> String {
+    match data {
+        Data::Null => String::new(),
+        Data::Scalar(scalar) => inline_code(scalar),
+        Data::Seq(items) => items.iter().map(inline).collect::<Vec<_>>().join(", "),
+        Data::Map(entries) => entries
+            .iter()
+            .map(|(key, value)| format!("{}: {}", escape_key(key), inline(value)))
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+/// Turns non-scalar data into a block element.
+fn block(data: Data) -> Node {
+    match data {
+        Data::Seq(items)
+            if !items.is_empty() && items.iter().all(|item| matches!(item, Data::Map(_))) =>
+        {
+            let mut headers: Vec<String> = Vec::new();
+            for item in &items {
+                if let Data::Map(entries) = item {
+                    for (key, _) in entries {
+                        if !headers.contains(key) {
+                            headers.push(key.clone());
+                        }
+                    }
+                }
+            }
+
+            let mut table = Table::from(headers.iter().map(|key| escape_key(key)).collect());
+            for item in &items {
+                if let Data::Map(entries) = item {
+                    table.add_row(
+                        headers
+                            .iter()
+                            .map(|header| {
+                                entries
+                                    .iter()
+                                    .find(|(key, _)| key == header)
+                                    .map_or_else(String::new, |(_, value)| inline(value))
+                            })
+                            .collect(),
+                    );
+                }
+            }
+            Node::Table(table)
+        }
+        Data::Seq(items) => Node::List(List::unordered_with(
+            items.into_iter().map(|value| item(None, value)).collect(),
+        )),
+        Data::Map(entries) => Node::List(List::unordered_with(
+            entries
+                .into_iter()
+                .map(|(key, value)| item(Some(key), value))
+                .collect(),
+        )),
+        Data::Null => Node::Text(String::new()),
+        Data::Scalar(scalar) => Node::Paragraph(Paragraph::from(inline_code(&scalar))),
+    }
+}
+
+/// Escapes a map key or field name, which may contain markdown metacharacters
+/// or look like a list marker at the start of an item.
+fn escape_key(key: &str) -> String {
+    let escaped = escape_text(key);
+    let digits = escaped.chars().take_while(char::is_ascii_digit).count();
+
+    if escaped.starts_with(['-', '+']) {
+        format!("\\{}", escaped)
+    } else if digits > 0 && escaped[digits..].starts_with(['.', ')']) {
+        format!("{}\\{}", &escaped[..digits], &escaped[digits..])
+    } else {
+        escaped
+    }
+}
+
+/// Turns data into a list item, nesting non-scalar values as a list.
+///
+/// Keyed nested values are made of a `key:` paragraph followed by the nested
+/// list, embedded using a compact [Include](../types/include/struct.Include.html).
+fn item(key: Option<String>, value: Data) -> Node {
+    let label = key.map(|key| format!("{}:", escape_key(&key)));
+
+    let nested = match value {
+        Data::Null => return Node::Text(label.unwrap_or_default()),
+        Data::Scalar(scalar) => {
+            return Node::Text(match label {
+                Some(label) => format!("{} {}", label, inline_code(&scalar)),
+                None => inline_code(&scalar),
+            })
+        }
+        Data::Seq(items) if items.is_empty() => {
+            return Node::Text(match label {
+                Some(label) => format!("{} []", label),
+                None => "[]".to_string(),
+            })
+        }
+        Data::Map(entries) if entries.is_empty() => {
+            return Node::Text(match label {
+                Some(label) => format!("{} {{}}", label),
+                None => "{}".to_string(),
+            })
+        }
+        Data::Seq(items) => {
+            List::unordered_with(items.into_iter().map(|value| item(None, value)).collect())
+        }
+        Data::Map(entries) => List::unordered_with(
+            entries
+                .into_iter()
+                .map(|(key, value)| item(Some(key), value))
+                .collect(),
+        ),
+    };
+
+    match label {
+        Some(label) => {
+            let mut blocks = Markdown::new();
+            blocks.set_compact(true).paragraph(label).list(nested);
+            Node::Include(Include::from(blocks))
+        }
+        None => Node::List(nested),
+    }
+}
+
+/// Serializes values into [Data].
+struct Serializer;
+
+/// Collects the elements of sequences, tuples and variants.
+struct SeqSerializer {
+    variant: Option<&'static str>,
+    items: Vec<Data>,
+}
+
+/// Collects the entries of maps, structs and struct variants.
+struct MapSerializer {
+    variant: Option<&'static str>,
+    entries: Vec<(String, Data)>,
+    key: Option<String>,
+}
+
+/// Wraps data into a single entry map if it belongs to an enum variant.
+fn with_variant(variant: Option<&'static str>, data: Data) -> Data {
+    match variant {
+        Some(variant) => Data::Map(vec![(variant.to_string(), data)]),
+        None => data,
+    }
+}
+
+macro_rules! serialize_scalars {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Data, SerializeError> {
+                Ok(Data::Scalar(v.to_string()))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Data;
+    type Error = SerializeError;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = MapSerializer;
+
+    serialize_scalars!(
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str
+    );
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Data, SerializeError> {
+        Ok(Data::Seq(
+            v.iter()
+                .map(|byte| Data::Scalar(byte.to_string()))
+                .collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Data, SerializeError> {
+        Ok(Data::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Data, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Data, SerializeError> {
+        Ok(Data::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Data, SerializeError> {
+        Ok(Data::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Data, SerializeError> {
+        Ok(Data::Scalar(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Data, SerializeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Data, SerializeError> {
+        Ok(with_variant(Some(variant), value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer {
+            variant: None,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, SerializeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, SerializeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, SerializeError> {
+        Ok(SeqSerializer {
+            variant: Some(variant),
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer, SerializeError> {
+        Ok(MapSerializer {
+            variant: None,
+            entries: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<MapSerializer, SerializeError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<MapSerializer, SerializeError> {
+        Ok(MapSerializer {
+            variant: Some(variant),
+            entries: Vec::with_capacity(len),
+            key: None,
+        })
+    }
+}
+
+impl SeqSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.items.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Data, SerializeError> {
+        Ok(with_variant(self.variant, Data::Seq(self.items)))
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Data;
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Data, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Data;
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Data, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Data;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Data, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqSerializer {
+    type Ok = Data;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Data, SerializeError> {
+        self.finish()
+    }
+}
+
+impl MapSerializer {
+    fn entry<T: Serialize + ?Sized>(
+        &mut self,
+        key: String,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.entries.push((key, value.serialize(Serializer)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Data, SerializeError> {
+        Ok(with_variant(self.variant, Data::Map(self.entries)))
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Data;
+    type Error = SerializeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match key.serialize(Serializer)? {
+            Data::Scalar(key) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            _ => Err(ser::Error::custom("map keys must be scalars")),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ser::Error::custom("map value without a key"))?;
+        self.entry(key, value)
+    }
+
+    fn end(self) -> Result<Data, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Data;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.entry(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Data, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for MapSerializer {
+    type Ok = Data;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.entry(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Data, SerializeError> {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    struct Crate {
+        name: &'static str,
+        downloads: u32,
+        yanked: bool,
+    }
+
+    #[test]
+    fn test_scalar() {
+        assert_eq!(to_markdown(&42).unwrap().render(), "`42`\n");
+        assert_eq!(to_markdown(&"a`b").unwrap().render(), "`` a`b ``\n");
+        assert_eq!(to_markdown(&()).unwrap().render(), "");
+    }
+
+    #[test]
+    fn test_sequence_of_structs_is_table() {
+        let crates = vec![
+            Crate {
+                name: "serde",
+                downloads: 300,
+                yanked: false,
+            },
+            Crate {
+                name: "syn",
+                downloads: 250,
+                yanked: true,
+            },
+        ];
+
+        assert_eq!(
+            to_markdown(&crates).unwrap().render(),
+            "| name    | downloads | yanked  |\n\
+            | ------- | --------- | ------- |\n\
+            | `serde` | `300`     | `false` |\n\
+            | `syn`   | `250`     | `true`  |\n"
+        );
+    }
+
+    #[test]
+    fn test_table_merges_keys() {
+        let value = json!([{ "a": 1 }, { "b": [1, 2] }]);
+
+        assert_eq!(
+            to_markdown(&value).unwrap().render(),
+            "| a   | b        |\n| --- | -------- |\n| `1` |          |\n|     | `1`, `2` |\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_map() {
+        let value = json!({
+            "name": "markdown-builder",
+            "features": ["serde", "derive"],
+            "authors": { "main": "Erb3" },
+            "empty": []
+        });
+
+        assert_eq!(
+            to_markdown(&value).unwrap().render(),
+            "- authors:\n  - main: `Erb3`\n- empty: []\n\
+            - features:\n  - `serde`\n  - `derive`\n- name: `markdown-builder`\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_values_are_nodes() {
+        let doc = to_markdown(&json!({ "authors": { "main": "Erb3" } })).unwrap();
+
+        let Node::List(list) = &doc.elements[0] else {
+            panic!("expected a list");
+        };
+        let Node::Include(include) = &list.items[0] else {
+            panic!("expected an include");
+        };
+        assert!(matches!(include.document.elements[1], Node::List(_)));
+        assert_eq!(
+            doc.nodes()
+                .filter(|node| matches!(node, Node::List(_)))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_keys_are_escaped() {
+        let value = json!({ "*bold*": 1, "- item": 2, "1. first": 3 });
+
+        assert_eq!(
+            to_markdown(&value).unwrap().render(),
+            "- \\*bold\\*: `1`\n- \\- item: `2`\n- 1\\. first: `3`\n"
+        );
+    }
+
+    #[test]
+    fn test_sequence_of_sequences() {
+        assert_eq!(
+            to_markdown(&vec![vec![1, 2], vec![3]]).unwrap().render(),
+            "- - `1`\n  - `2`\n- - `3`\n"
+        );
+    }
+
+    #[test]
+    fn test_enum_variants() {
+        #[derive(Serialize)]
+        enum Status {
+            Ok,
+            Failed { code: i32 },
+        }
+
+        assert_eq!(
+            to_markdown(&vec![Status::Ok, Status::Failed { code: 2 }])
+                .unwrap()
+                .render(),
+            "- `Ok`\n- - Failed:\n    - code: `2`\n"
+        );
+    }
+
+    #[test]
+    fn test_non_scalar_key() {
+        let mut map = BTreeMap::new();
+        map.insert(vec![1], 1);
+
+        assert_eq!(
+            to_markdown(&map).unwrap_err().to_string(),
+            "map keys must be scalars"
+        );
+    }
+}
@@ -0,0 +1,909 @@
+//! Contains a renderer that prints markdown documents to a terminal.
+//!
+//! Instead of markdown syntax, the renderer emits ANSI SGR escape codes for
+//! styling and OSC 8 escape sequences for hyperlinks. Both can be turned off
+//! using [no_color](struct.TerminalRenderer.html#method.no_color), e.g. when
+//! the output is piped into a file.
+
+use crate::{
+    traits::MarkdownElement,
+    transforms::Inline,
+    types::{
+        checkbox::{Checkbox, Task},
+        definition_list::DefinitionList,
+        details::Details,
+        header::Header,
+        image::Image,
+        list::{List, ListVariant},
+        markdown::Markdown,
+        node::Node,
+        paragraph::Paragraph,
+        quote::Quote,
+        table::{Alignment, Table},
+    },
+};
+
+const RESET: &str = "\x1b[0m";
+const CODE_BACKGROUND: &str = "48;5;236";
+const CHECKED: &str = "☑";
+const UNCHECKED: &str = "☐";
+const BULLET: &str = "•";
+const QUOTE_BAR: &str = "│";
+const DISCLOSURE: &str = "▾";
+
+/// The SGR codes used for headers, indexed by header level.
+const HEADER_STYLES: [&str; 6] = ["1;4;35", "1;35", "1;36", "1;34", "1;32", "1;33"];
+
+/// Renders markdown documents and elements as styled terminal output.
+///
+/// Built-in elements are rendered using their own styling, every other element
+/// is rendered as markdown text with its inline markup (bold, italic,
+/// strikethrough and code) converted to escape codes. Footers are omitted, as
+/// links and images are rendered with their targets in place.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TerminalRenderer {
+    color: bool,
+    width: usize,
+}
+
+impl Default for TerminalRenderer {
+    /// Returns a renderer with colors enabled and a width of 80 columns.
+    fn default() -> Self {
+        Self {
+            color: true,
+            width: 80,
+        }
+    }
+}
+
+impl TerminalRenderer {
+    /// Creates a new default `TerminalRenderer`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disables all escape sequences.
+    ///
+    /// Inline markup is kept as-is, links and images are followed by their
+    /// target in parentheses.
+    pub fn no_color(mut self) -> Self {
+        self.color = false;
+        self
+    }
+
+    /// Sets whether escape sequences should be emitted.
+    pub fn set_color(mut self, value: bool) -> Self {
+        self.color = value;
+        self
+    }
+
+    /// Sets the terminal width that paragraphs are wrapped at.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Renders the whole document.
+    ///
+    /// Elements are separated by a blank line.
+    pub fn render(&self, doc: &Markdown) -> String {
+        let blocks: Vec<String> = doc
+            .elements
+            .iter()
+            .map(|node| self.render_node(node))
+            .collect();
+
+        if blocks.is_empty() {
+            return String::new();
+        }
+
+        format!("{}\n", blocks.join("\n\n"))
+    }
+
+    /// Renders a single element, without a trailing newline.
+    pub fn render_element<T>(&self, element: &T) -> String
+    where
+        T: MarkdownElement + Clone + 'static,
+    {
+        self.render_node(&Node::from_element(element.clone()))
+    }
+
+    /// Renders a single node, without a trailing newline.
+    pub fn render_node(&self, node: &Node) -> String {
+        match node {
+            Node::Header(header) => self.header(header),
+            Node::Paragraph(paragraph) => self.paragraph(paragraph),
+            Node::Link(link) => self.link(&link.text, &link.url),
+            Node::Autolink(link) => self.link(&link.url, &link.url),
+            Node::Image(image) => self.image(image),
+            Node::List(list) => self.list(list),
+            Node::Checkbox(checkbox) => self.checkbox(checkbox),
+            Node::Task(task) => self.task(task),
+            Node::Quote(quote) => self.quote(quote),
+            Node::Include(include) => include
+                .document
+                .elements
+                .iter()
+                .map(|node| self.render_node(node))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            Node::Table(table) => self.table(table),
+            Node::DefinitionList(list) => self.definition_list(list),
+            Node::Details(details) => self.details(details),
+            Node::MathBlock(math) => self.code_lines(&math.tex.lines().collect::<Vec<_>>()),
+            Node::InlineMath(math) => self.prose(&math.tex.to_inline(), false),
+            Node::Diagram(diagram) => self.code_lines(&diagram.source.lines().collect::<Vec<_>>()),
+            Node::Section(section) => std::iter::once(self.header(&section.header))
+                .chain(section.children.iter().map(|node| self.render_node(node)))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            Node::Text(_) | Node::Custom(_) => {
+                self.text(node.render().trim_end_matches('\n'), false)
+            }
+        }
+    }
+
+    fn paragraph(&self, paragraph: &Paragraph) -> String {
+        if paragraph
+            .children
+            .iter()
+            .all(|child| matches!(child, Node::Text(_)))
+        {
+            return self.text(&paragraph.text(), true);
+        }
+
+        paragraph
+            .children
+            .iter()
+            .map(|child| match child {
+                Node::Text(text) => self.prose(text, false),
+                Node::Link(link) => self.link(&link.text, &link.url),
+                Node::Image(image) => self.image(image),
+                child => self.render_node(child),
+            })
+            .collect()
+    }
+
+    fn header(&self, header: &Header) -> String {
+        let level = header.level.value();
+        if self.color {
+            format!("\x1b[{}m{}{}", HEADER_STYLES[level - 1], header.text, RESET)
+        } else {
+            format!("{} {}", "#".repeat(level), header.text)
+        }
+    }
+
+    fn link(&self, text: &str, url: &str) -> String {
+        if self.color {
+            format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+        } else if text == url {
+            url.to_string()
+        } else {
+            format!("{} ({})", text, url)
+        }
+    }
+
+    fn image(&self, image: &Image) -> String {
+        self.link(&format!("[image: {}]", image.text), &image.url)
+    }
+
+    fn checkbox(&self, checkbox: &Checkbox) -> String {
+        match checkbox {
+            Checkbox::Checked(text) if self.color => {
+                format!("\x1b[32m{}{} {}", CHECKED, RESET, self.prose(text, false))
+            }
+            Checkbox::Checked(text) => format!("{} {}", CHECKED, text),
+            Checkbox::Unchecked(text) => format!("{} {}", UNCHECKED, self.prose(text, false)),
+        }
+    }
+
+    fn task(&self, task: &Task) -> String {
+        let checkbox = self.checkbox(&task.checkbox);
+        if task.subtasks.items.is_empty() {
+            return checkbox;
+        }
+
+        let inner = Self {
+            width: self.width.saturating_sub(2).max(1),
+            ..self.clone()
+        };
+        let subtasks: Vec<String> = inner
+            .list(&task.subtasks)
+            .lines()
+            .map(|line| format!("  {}", line))
+            .collect();
+
+        format!("{}\n{}", checkbox, subtasks.join("\n"))
+    }
+
+    fn list(&self, list: &List) -> String {
+        let mut lines = Vec::new();
+
+        for (idx, (item, marker)) in list.items.iter().zip(list.markers()).enumerate() {
+            if list.loose && idx != 0 {
+                lines.push(String::new());
+            }
+
+            let is_checkbox = matches!(item, Node::Checkbox(_) | Node::Task(_));
+            let marker = match (&list.variant, is_checkbox) {
+                (ListVariant::Ordered, _) => marker,
+                (ListVariant::Unordered, true) => String::new(),
+                (ListVariant::Unordered, false) => format!("{} ", BULLET),
+            };
+
+            let indent = marker.chars().count();
+            let inner = Self {
+                width: self.width.saturating_sub(indent).max(1),
+                ..self.clone()
+            };
+
+            for (line_idx, line) in inner.render_node(item).lines().enumerate() {
+                if line_idx == 0 {
+                    lines.push(format!("{}{}", marker, line));
+                } else {
+                    lines.push(format!("{}{}", " ".repeat(indent), line));
+                }
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    fn quote(&self, quote: &Quote) -> String {
+        let inner = Self {
+            width: self.width.saturating_sub(2).max(1),
+            ..self.clone()
+        };
+        let prefix = if self.color {
+            format!("\x1b[2m{}{}", QUOTE_BAR, RESET)
+        } else {
+            ">".to_string()
+        };
+
+        let blocks: Vec<String> = quote
+            .children
+            .iter()
+            .map(|child| inner.render_node(child))
+            .collect();
+
+        blocks
+            .join("\n\n")
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    prefix.clone()
+                } else {
+                    format!("{} {}", prefix, line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn definition_list(&self, list: &DefinitionList) -> String {
+        let inner = Self {
+            width: self.width.saturating_sub(4).max(1),
+            ..self.clone()
+        };
+
+        list.items
+            .iter()
+            .map(|item| {
+                let term = if self.color {
+                    format!("\x1b[1m{}{}", item.term, RESET)
+                } else {
+                    format!("**{}**", item.term)
+                };
+                let definitions = item.definitions.iter().map(|definition| {
+                    definition
+                        .iter()
+                        .map(|node| inner.render_node(node))
+                        .collect::<Vec<_>>()
+                        .join("\n\n")
+                        .lines()
+                        .map(|line| match line.is_empty() {
+                            true => String::new(),
+                            false => format!("    {}", line),
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                });
+
+                std::iter::once(term)
+                    .chain(definitions)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn details(&self, details: &Details) -> String {
+        let inner = Self {
+            width: self.width.saturating_sub(2).max(1),
+            ..self.clone()
+        };
+        let summary = if self.color {
+            format!("\x1b[1m{} {}{}", DISCLOSURE, details.summary, RESET)
+        } else {
+            format!("{} {}", DISCLOSURE, details.summary)
+        };
+
+        let children = details
+            .children
+            .iter()
+            .map(|child| inner.render_node(child))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+            .lines()
+            .map(|line| match line.is_empty() {
+                true => String::new(),
+                false => format!("  {}", line),
+            })
+            .collect::<Vec<_>>();
+
+        std::iter::once(summary)
+            .chain(children)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn table(&self, table: &Table) -> String {
+        if !self.color {
+            return table.render().trim_end_matches('\n').to_string();
+        }
+
+        let columns = table.headers.len();
+        let cell = |row: &[String], index: usize| row.get(index).cloned().unwrap_or_default();
+        let widths: Vec<usize> = (0..columns)
+            .map(|index| {
+                table
+                    .rows
+                    .iter()
+                    .map(|row| cell(row, index).chars().count())
+                    .chain(std::iter::once(table.headers[index].chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let line = |row: &[String]| {
+            (0..columns)
+                .map(|index| {
+                    let text = cell(row, index);
+                    let padding = " ".repeat(widths[index] - text.chars().count());
+                    if table.alignment(index) == Alignment::Right {
+                        format!("{}{}", padding, text)
+                    } else {
+                        format!("{}{}", text, padding)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(&format!(" {} ", QUOTE_BAR))
+                .trim_end()
+                .to_string()
+        };
+
+        let mut lines = vec![format!("\x1b[1m{}{}", line(&table.headers), RESET)];
+        lines.push(
+            widths
+                .iter()
+                .map(|width| "─".repeat(*width))
+                .collect::<Vec<_>>()
+                .join("─┼─"),
+        );
+        lines.extend(table.rows.iter().map(|row| line(row)));
+        lines.join("\n")
+    }
+
+    /// Renders text that may contain fenced code blocks.
+    fn text(&self, text: &str, wrap: bool) -> String {
+        let mut blocks = Vec::new();
+        let mut prose: Vec<&str> = Vec::new();
+        let mut code: Option<Vec<&str>> = None;
+
+        for line in text.lines() {
+            if is_fence(line) {
+                match code.take() {
+                    Some(mut lines) => {
+                        lines.push(line);
+                        blocks.push(self.code_block(&lines));
+                    }
+                    None => {
+                        if !prose.is_empty() {
+                            blocks.push(self.prose(&prose.join("\n"), wrap));
+                            prose.clear();
+                        }
+                        code = Some(vec![line]);
+                    }
+                }
+            } else if let Some(lines) = code.as_mut() {
+                lines.push(line);
+            } else {
+                prose.push(line);
+            }
+        }
+
+        if let Some(lines) = code {
+            blocks.push(self.code_block(&lines));
+        }
+
+        if !prose.is_empty() {
+            blocks.push(self.prose(&prose.join("\n"), wrap));
+        }
+
+        blocks.join("\n")
+    }
+
+    /// Renders the lines of a fenced code block, including its fences.
+    fn code_block(&self, lines: &[&str]) -> String {
+        if !self.color {
+            return lines.join("\n");
+        }
+
+        let mut code = lines.get(1..).unwrap_or_default();
+        if let Some((last, rest)) = code.split_last() {
+            if is_fence(last) {
+                code = rest;
+            }
+        }
+
+        self.code_lines(code)
+    }
+
+    /// Renders lines of code without fences, e.g. the source of a math block.
+    fn code_lines(&self, code: &[&str]) -> String {
+        if !self.color {
+            return code.join("\n");
+        }
+
+        let width = code
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        code.iter()
+            .map(|line| {
+                format!(
+                    "\x1b[{}m {:<width$} {}",
+                    CODE_BACKGROUND,
+                    line,
+                    RESET,
+                    width = width
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Renders text with inline markup, optionally word wrapped.
+    fn prose(&self, text: &str, wrap: bool) -> String {
+        let chars = if self.color {
+            parse_inline(text)
+        } else {
+            text.chars().map(|c| (c, Style::default())).collect()
+        };
+
+        let lines = if wrap {
+            wrap_words(&chars, self.width)
+        } else {
+            chars
+                .split(|(c, _)| *c == '\n')
+                .map(|line| line.to_vec())
+                .collect()
+        };
+
+        lines
+            .iter()
+            .map(|line| emit_line(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Returns whether the line opens or closes a fenced code block.
+fn is_fence(line: &str) -> bool {
+    line.trim_start().starts_with("```")
+}
+
+/// The inline style of a single character.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Style {
+    bold: bool,
+    italic: bool,
+    strikethrough: bool,
+    code: bool,
+}
+
+impl Style {
+    fn sgr(&self) -> Option<String> {
+        let codes: Vec<&str> = [
+            (self.bold, "1"),
+            (self.italic, "3"),
+            (self.strikethrough, "9"),
+            (self.code, CODE_BACKGROUND),
+        ]
+        .iter()
+        .filter(|(active, _)| *active)
+        .map(|(_, code)| *code)
+        .collect();
+
+        if codes.is_empty() {
+            None
+        } else {
+            Some(format!("\x1b[{}m", codes.join(";")))
+        }
+    }
+}
+
+/// Strips inline markup from the text, returning each character along with
+/// its style.
+///
+/// Markers are only treated as such when they are closed later on.
+fn parse_inline(text: &str) -> Vec<(char, Style)> {
+    let chars: Vec<char> = text.chars().collect();
+    let closes = |from: usize, marker: &[char]| {
+        from <= chars.len() && chars[from..].windows(marker.len()).any(|w| w == marker)
+    };
+
+    let mut style = Style::default();
+    let mut styled = Vec::new();
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let rest = &chars[idx..];
+
+        if rest[0] == '`' && (style.code || closes(idx + 1, &['`'])) {
+            style.code = !style.code;
+            idx += 1;
+            continue;
+        }
+
+        if !style.code {
+            if rest.starts_with(&['*', '*']) && (style.bold || closes(idx + 2, &['*', '*'])) {
+                style.bold = !style.bold;
+                idx += 2;
+                continue;
+            }
+
+            if rest.starts_with(&['~', '~'])
+                && (style.strikethrough || closes(idx + 2, &['~', '~']))
+            {
+                style.strikethrough = !style.strikethrough;
+                idx += 2;
+                continue;
+            }
+
+            if rest[0] == '*' && (style.italic || closes(idx + 1, &['*'])) {
+                style.italic = !style.italic;
+                idx += 1;
+                continue;
+            }
+        }
+
+        styled.push((rest[0], style));
+        idx += 1;
+    }
+
+    styled
+}
+
+/// Word wraps styled characters at the given width.
+///
+/// Newlines are treated as regular whitespace. Words longer than the width are
+/// put on their own line.
+fn wrap_words(chars: &[(char, Style)], width: usize) -> Vec<Vec<(char, Style)>> {
+    let mut lines = Vec::new();
+    let mut line: Vec<(char, Style)> = Vec::new();
+
+    for word in chars
+        .split(|(c, _)| *c == ' ' || *c == '\n')
+        .filter(|word| !word.is_empty())
+    {
+        if !line.is_empty() && line.len() + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+
+        if let Some((_, previous)) = line.last() {
+            // Keep the space styled if it sits inside a styled span.
+            let style = if *previous == word[0].1 {
+                *previous
+            } else {
+                Style::default()
+            };
+            line.push((' ', style));
+        }
+
+        line.extend_from_slice(word);
+    }
+
+    lines.push(line);
+    lines
+}
+
+/// Writes a line of styled characters, resetting the style at its end.
+fn emit_line(line: &[(char, Style)]) -> String {
+    let mut out = String::new();
+    let mut current = Style::default();
+
+    for (c, style) in line {
+        if *style != current {
+            if current != Style::default() {
+                out.push_str(RESET);
+            }
+            if let Some(sgr) = style.sgr() {
+                out.push_str(&sgr);
+            }
+            current = *style;
+        }
+        out.push(*c);
+    }
+
+    if current != Style::default() {
+        out.push_str(RESET);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        transforms::Bold, CodeBlock, Diagram, ImageBuilder, LinkBuilder, ListBuilder, MathBlock,
+        Paragraph,
+    };
+
+    #[test]
+    fn test_header_color() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").h3("Section");
+
+        assert_eq!(
+            TerminalRenderer::new().render(&doc),
+            "\x1b[1;4;35mTitle\x1b[0m\n\n\x1b[1;36mSection\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_header_no_color() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").h3("Section");
+
+        assert_eq!(
+            TerminalRenderer::new().no_color().render(&doc),
+            "# Title\n\n### Section\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_document() {
+        assert_eq!(TerminalRenderer::new().render(&Markdown::new()), "");
+    }
+
+    #[test]
+    fn test_table() {
+        let table = Table::with(vec!["Name", "Age"], vec![vec!["Alice", "30"]]);
+
+        assert_eq!(
+            TerminalRenderer::new().render_element(&table),
+            "\x1b[1mName  │ Age\x1b[0m\n──────┼────\nAlice │ 30"
+        );
+        assert_eq!(
+            TerminalRenderer::new().no_color().render_element(&table),
+            "| Name  | Age |\n| ----- | --- |\n| Alice | 30  |"
+        );
+    }
+
+    #[test]
+    fn test_inline_styles() {
+        let renderer = TerminalRenderer::new();
+        assert_eq!(
+            renderer.render_element(&Paragraph::from(format!("a {} c", "b".to_bold()))),
+            "a \x1b[1mb\x1b[0m c"
+        );
+        assert_eq!(
+            renderer.render_element(&Paragraph::from("*a* ~~b~~ `c`")),
+            "\x1b[3ma\x1b[0m \x1b[9mb\x1b[0m \x1b[48;5;236mc\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_unclosed_marker_is_kept() {
+        assert_eq!(
+            TerminalRenderer::new().render_element(&Paragraph::from("2 * 3")),
+            "2 * 3"
+        );
+    }
+
+    #[test]
+    fn test_inline_styles_no_color() {
+        assert_eq!(
+            TerminalRenderer::new()
+                .no_color()
+                .render_element(&Paragraph::from("**a** b")),
+            "**a** b"
+        );
+    }
+
+    #[test]
+    fn test_paragraph_wrapping() {
+        let renderer = TerminalRenderer::new().no_color().width(10);
+        assert_eq!(
+            renderer.render_element(&Paragraph::from("one two three four")),
+            "one two\nthree four"
+        );
+    }
+
+    #[test]
+    fn test_paragraph_wrapping_ignores_escape_codes() {
+        let renderer = TerminalRenderer::new().width(7);
+        assert_eq!(
+            renderer.render_element(&Paragraph::from("**one** two")),
+            "\x1b[1mone\x1b[0m two"
+        );
+    }
+
+    #[test]
+    fn test_link() {
+        let link = LinkBuilder::new()
+            .url("https://example.com")
+            .text("Example")
+            .build();
+
+        assert_eq!(
+            TerminalRenderer::new().render_element(&link),
+            "\x1b]8;;https://example.com\x1b\\Example\x1b]8;;\x1b\\"
+        );
+        assert_eq!(
+            TerminalRenderer::new().no_color().render_element(&link),
+            "Example (https://example.com)"
+        );
+    }
+
+    #[test]
+    fn test_footer_link_is_inlined() {
+        let mut doc = Markdown::new();
+        doc.link(
+            LinkBuilder::new()
+                .url("https://example.com")
+                .text("Example")
+                .footer()
+                .build(),
+        );
+
+        assert_eq!(
+            TerminalRenderer::new().no_color().render(&doc),
+            "Example (https://example.com)\n"
+        );
+    }
+
+    #[test]
+    fn test_image_no_color() {
+        let image = ImageBuilder::new()
+            .url("https://example.com/cat.png")
+            .text("A cat")
+            .build();
+
+        assert_eq!(
+            TerminalRenderer::new().no_color().render_element(&image),
+            "[image: A cat] (https://example.com/cat.png)"
+        );
+    }
+
+    #[test]
+    fn test_checkbox_list() {
+        let list = ListBuilder::new()
+            .checkbox("Eat", true)
+            .checkbox("Sleep", false)
+            .unordered();
+
+        assert_eq!(
+            TerminalRenderer::new().no_color().render_element(&list),
+            "☑ Eat\n☐ Sleep"
+        );
+        assert_eq!(
+            TerminalRenderer::new().render_element(&list),
+            "\x1b[32m☑\x1b[0m Eat\n☐ Sleep"
+        );
+    }
+
+    #[test]
+    fn test_nested_tasks() {
+        let list = ListBuilder::new()
+            .task(
+                "Deploy",
+                false,
+                ListBuilder::new().checkbox("Build", true).unordered(),
+            )
+            .unordered();
+
+        assert_eq!(
+            TerminalRenderer::new().no_color().render_element(&list),
+            "☐ Deploy\n  ☑ Build"
+        );
+    }
+
+    #[test]
+    fn test_list_continuation_is_indented() {
+        let list = ListBuilder::new()
+            .append(Paragraph::from("one two three"))
+            .append("four")
+            .ordered();
+
+        assert_eq!(
+            TerminalRenderer::new()
+                .no_color()
+                .width(10)
+                .render_element(&list),
+            "1. one two\n   three\n2. four"
+        );
+    }
+
+    #[test]
+    fn test_quote() {
+        let quote = Quote::with(vec![Paragraph::from("one two"), Paragraph::from("three")]);
+
+        assert_eq!(
+            TerminalRenderer::new()
+                .no_color()
+                .width(5)
+                .render_element(&quote),
+            "> one\n> two\n>\n> three"
+        );
+        assert_eq!(
+            TerminalRenderer::new().render_element(&quote),
+            "\x1b[2m│\x1b[0m one two\n\x1b[2m│\x1b[0m\n\x1b[2m│\x1b[0m three"
+        );
+    }
+
+    #[test]
+    fn test_code_block_background() {
+        let renderer = TerminalRenderer::new();
+        assert_eq!(
+            renderer.render_element(&"fn main() {}\nlet a;".to_code_block_with_language("rust")),
+            "\x1b[48;5;236m fn main() {} \x1b[0m\n\x1b[48;5;236m let a;       \x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_code_block_no_color() {
+        let code = "let a;".to_code_block();
+        assert_eq!(
+            TerminalRenderer::new().no_color().render_element(&code),
+            code
+        );
+    }
+
+    #[test]
+    fn test_math_and_diagram_blocks() {
+        let renderer = TerminalRenderer::new();
+        assert_eq!(
+            renderer.render_node(&Node::MathBlock(MathBlock::from("a = b\nc = d"))),
+            "\x1b[48;5;236m a = b \x1b[0m\n\x1b[48;5;236m c = d \x1b[0m"
+        );
+        assert_eq!(
+            renderer.render_node(&Node::Diagram(Diagram::mermaid("graph TD"))),
+            "\x1b[48;5;236m graph TD \x1b[0m"
+        );
+        assert_eq!(
+            TerminalRenderer::new()
+                .no_color()
+                .render_node(&Node::MathBlock(MathBlock::from("a = b\nc = d"))),
+            "a = b\nc = d"
+        );
+    }
+
+    #[test]
+    fn test_empty_math_and_diagram_blocks() {
+        let renderer = TerminalRenderer::new();
+        assert_eq!(
+            renderer.render_node(&Node::MathBlock(MathBlock::from(""))),
+            ""
+        );
+        assert_eq!(
+            renderer.render_node(&Node::Diagram(Diagram::mermaid(""))),
+            ""
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Contains Markdown trait definitions.
 
+use crate::types::{markdown::Markdown, section::Section, table::Table};
 use dyn_clonable::clonable;
-use std::fmt;
+use std::{any::Any, fmt};
 
 /// A [MarkdownElement](trait.MarkdownElement.html) that can be rendered as a
 /// footer value.
@@ -15,6 +16,18 @@ pub trait AsFooter {
 pub trait MarkdownElement: Clone + fmt::Debug {
     /// Renders the element to markdown.
     fn render(&self) -> String;
+
+    /// Returns the element as `Any`, allowing renderers to downcast it to its
+    /// concrete type.
+    ///
+    /// Returns `None` by default, in which case the element is treated as a
+    /// custom element.
+    fn as_any(&self) -> Option<&dyn Any>
+    where
+        Self: 'static,
+    {
+        None
+    }
 }
 
 /// Implemented for all types that do implement `Display`.
@@ -27,6 +40,13 @@ where
     fn render(&self) -> String {
         format!("{}", self)
     }
+
+    fn as_any(&self) -> Option<&dyn Any>
+    where
+        Self: 'static,
+    {
+        Some(self)
+    }
 }
 
 // Implemented for easier `Box` handling.
@@ -38,3 +58,215 @@ where
         Box::new(value)
     }
 }
+
+/// A type that can describe itself as markdown.
+///
+/// Usually implemented using `#[derive(ToMarkdown)]`, which requires the
+/// `derive` feature. Structs are rendered as a section containing a table of
+/// their fields, enums as a section describing the current variant.
+pub trait ToMarkdown {
+    /// Returns a section describing the value.
+    fn to_section(&self) -> Section;
+
+    /// Returns the column headers used when rendering many values as a table.
+    fn table_headers() -> Vec<String>
+    where
+        Self: Sized;
+
+    /// Returns the cells used when rendering the value as a table row.
+    fn table_row(&self) -> Vec<String>;
+
+    /// Returns a document containing the [section](trait.ToMarkdown.html#tymethod.to_section).
+    fn to_markdown(&self) -> Markdown {
+        let mut doc = Markdown::new();
+        doc.add(self.to_section());
+        doc
+    }
+
+    /// Returns the [section](trait.ToMarkdown.html#tymethod.to_section) as a
+    /// boxed element.
+    fn to_element(&self) -> Box<dyn MarkdownElement> {
+        Box::new(self.to_section())
+    }
+
+    /// Returns a table with one row per value.
+    fn to_table<'a, I>(values: I) -> Table
+    where
+        Self: Sized + 'a,
+        I: IntoIterator<Item = &'a Self>,
+    {
+        let mut table = Table::from(Self::table_headers());
+        for value in values {
+            table.add_row(value.table_row());
+        }
+        table
+    }
+}
+
+/// A value that can be shown inside a table cell.
+///
+/// Used by `#[derive(ToMarkdown)]` to render fields. Implement it for your own
+/// types to use them as fields.
+pub trait MarkdownValue {
+    /// Returns the value as markdown text.
+    fn to_markdown_value(&self) -> String;
+}
+
+macro_rules! impl_markdown_value {
+    ($($ty:ty),*) => {
+        $(
+            impl MarkdownValue for $ty {
+                fn to_markdown_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_markdown_value!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, str,
+    String
+);
+
+impl<T: MarkdownValue + ?Sized> MarkdownValue for &T {
+    fn to_markdown_value(&self) -> String {
+        (**self).to_markdown_value()
+    }
+}
+
+impl<T: MarkdownValue> MarkdownValue for Option<T> {
+    /// Renders `None` as an empty string.
+    fn to_markdown_value(&self) -> String {
+        self.as_ref()
+            .map(MarkdownValue::to_markdown_value)
+            .unwrap_or_default()
+    }
+}
+
+impl<T: MarkdownValue> MarkdownValue for Vec<T> {
+    /// Joins the values using commas.
+    fn to_markdown_value(&self) -> String {
+        self.iter()
+            .map(MarkdownValue::to_markdown_value)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl MarkdownValue for std::path::PathBuf {
+    fn to_markdown_value(&self) -> String {
+        self.display().to_string()
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::ToMarkdown;
+
+    /// How a server is reached.
+    #[allow(dead_code)]
+    #[derive(ToMarkdown)]
+    enum Protocol {
+        /// Plain text.
+        Http,
+        #[markdown(rename = "HTTPS")]
+        Https {
+            /// The certificate path.
+            certificate: String,
+        },
+    }
+
+    #[derive(ToMarkdown)]
+    struct User {
+        name: String,
+        #[markdown(code)]
+        role: &'static str,
+    }
+
+    /// The server configuration.
+    #[derive(ToMarkdown)]
+    #[markdown(rename = "Server")]
+    struct ServerConfig {
+        /// The port to listen on.
+        port: u16,
+        #[markdown(rename = "host name", code)]
+        host: String,
+        #[markdown(skip)]
+        #[allow(dead_code)]
+        secret: String,
+        timeout: Option<u32>,
+        /// The protocol to use.
+        #[markdown(nested)]
+        protocol: Protocol,
+        #[markdown(nested)]
+        users: Vec<User>,
+    }
+
+    fn config() -> ServerConfig {
+        ServerConfig {
+            port: 8080,
+            host: "localhost".to_string(),
+            secret: "hunter2".to_string(),
+            timeout: None,
+            protocol: Protocol::Https {
+                certificate: "cert.pem".to_string(),
+            },
+            users: vec![
+                User {
+                    name: "Alice".to_string(),
+                    role: "admin",
+                },
+                User {
+                    name: "Bob".to_string(),
+                    role: "guest",
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_derive_struct() {
+        assert_eq!(
+            config().to_markdown().render(),
+            "# Server\n\n\
+            The server configuration.\n\n\
+            | Field     | Value       | Description            |\n\
+            | --------- | ----------- | ---------------------- |\n\
+            | port      | 8080        | The port to listen on. |\n\
+            | host name | `localhost` |                        |\n\
+            | timeout   |             |                        |\n\n\
+            ## protocol\n\n\
+            The protocol to use.\n\n\
+            How a server is reached.\n\n\
+            `HTTPS`\n\n\
+            | Field       | Value    | Description           |\n\
+            | ----------- | -------- | --------------------- |\n\
+            | certificate | cert.pem | The certificate path. |\n\n\
+            ## users\n\n\
+            | name  | role    |\n\
+            | ----- | ------- |\n\
+            | Alice | `admin` |\n\
+            | Bob   | `guest` |\n"
+        );
+    }
+
+    #[test]
+    fn test_derive_unit_variant() {
+        assert_eq!(
+            Protocol::Http.to_element().render(),
+            "# Protocol\n\nHow a server is reached.\n\n`Http`\n\nPlain text.\n"
+        );
+        assert_eq!(Protocol::Http.to_markdown_value(), "Http");
+    }
+
+    #[test]
+    fn test_derive_table() {
+        let users = config().users;
+        assert_eq!(
+            User::to_table(&users).rows,
+            vec![vec!["Alice", "`admin`"], vec!["Bob", "`guest`"]]
+        );
+    }
+}
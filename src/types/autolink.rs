@@ -0,0 +1,188 @@
+use crate::{
+    flavor::Flavor,
+    types::link::{destination, escape_text, Link},
+};
+use std::fmt;
+
+/// A link showing its own URL, e.g. `<https://example.com>`.
+///
+/// URLs that cannot be written as an autolink, e.g. because they contain
+/// spaces or lack a scheme, are rendered as a regular link instead.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Autolink {
+    /// The URL or email address.
+    pub url: String,
+    /// Whether to render the URL without angle brackets in flavors supporting
+    /// extended autolinks.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bare: bool,
+}
+
+impl Autolink {
+    /// Creates a new `Autolink` for the given URL or email address.
+    pub fn from(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            bare: false,
+        }
+    }
+
+    /// Sets whether to render the URL without angle brackets in flavors
+    /// supporting extended autolinks.
+    pub fn set_bare(&mut self, bare: bool) -> &mut Self {
+        self.bare = bare;
+        self
+    }
+
+    /// Returns whether the URL can be written as `<url>`.
+    pub fn is_valid(&self) -> bool {
+        is_uri(&self.url) || is_email(&self.url)
+    }
+
+    /// Renders the autolink for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        if self.bare && flavor.supports_extended_autolinks() && is_bare_safe(&self.url) {
+            self.url.clone()
+        } else if self.is_valid() {
+            format!("<{}>", self.url)
+        } else {
+            format!(
+                "[{}]({})",
+                escape_text(&self.url),
+                destination(&self.url, None)
+            )
+        }
+    }
+}
+
+impl Link {
+    /// Creates a new [Autolink](../autolink/struct.Autolink.html) for the given
+    /// URL or email address.
+    pub fn autolink(url: impl Into<String>) -> Autolink {
+        Autolink::from(url)
+    }
+}
+
+impl fmt::Display for Autolink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
+/// Returns whether `url` is an absolute URI as defined by CommonMark: a scheme
+/// of 2 to 32 characters, a colon and no whitespace, controls or `<>`.
+fn is_uri(url: &str) -> bool {
+    let Some((scheme, rest)) = url.split_once(':') else {
+        return false;
+    };
+
+    (2..=32).contains(&scheme.len())
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c))
+        && rest
+            .chars()
+            .all(|c| !c.is_ascii_whitespace() && !c.is_control() && c != '<' && c != '>')
+}
+
+/// Returns whether `email` is an email address as defined by CommonMark.
+fn is_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+
+    let is_label = |label: &str| {
+        (1..=63).contains(&label.len())
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+
+    !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(c))
+        && domain.split('.').all(is_label)
+}
+
+/// Returns whether `url` is recognized as a GFM extended autolink as a whole.
+fn is_bare_safe(url: &str) -> bool {
+    let has_prefix = ["http://", "https://", "www."]
+        .iter()
+        .any(|prefix| url.starts_with(prefix));
+    let opening = url.matches('(').count();
+    let closing = url.matches(')').count();
+
+    has_prefix
+        && url.contains('.')
+        && !url.ends_with(|c: char| "?!.,:*_~".contains(c))
+        && opening == closing
+        && url
+            .chars()
+            .all(|c| !c.is_whitespace() && !c.is_control() && c != '<')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MarkdownElement;
+
+    #[test]
+    fn test_autolinks() {
+        assert_eq!(
+            Link::autolink("https://example.com").render(),
+            "<https://example.com>"
+        );
+        assert_eq!(
+            Link::autolink("mailto:hi@example.com").render(),
+            "<mailto:hi@example.com>"
+        );
+        assert_eq!(
+            Link::autolink("hi@example.com").render(),
+            "<hi@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_invalid_autolinks_fall_back() {
+        assert!(!Autolink::from("example.com").is_valid());
+        assert_eq!(
+            Autolink::from("example.com").render(),
+            "[example.com](example.com)"
+        );
+        assert_eq!(
+            Autolink::from("https://example.com/my file").render(),
+            "[https://example.com/my file](<https://example.com/my file>)"
+        );
+        assert_eq!(
+            Autolink::from("docs/my_file [draft].md").render(),
+            "[docs/my\\_file \\[draft\\].md](<docs/my_file [draft].md>)"
+        );
+        assert!(!Autolink::from("a:b").is_valid());
+        assert!(!Autolink::from("hi@-example.com").is_valid());
+    }
+
+    #[test]
+    fn test_bare_autolinks() {
+        let mut link = Autolink::from("https://example.com/a_(b)");
+        link.set_bare(true);
+
+        assert_eq!(
+            link.render_with(Flavor::GitHub),
+            "https://example.com/a_(b)"
+        );
+        assert_eq!(
+            link.render_with(Flavor::CommonMark),
+            "<https://example.com/a_(b)>"
+        );
+
+        let mut trailing = Autolink::from("https://example.com/.");
+        trailing.set_bare(true);
+        assert_eq!(
+            trailing.render_with(Flavor::GitHub),
+            "<https://example.com/.>"
+        );
+    }
+}
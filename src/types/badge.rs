@@ -0,0 +1,354 @@
+use crate::types::{image::Image, node::Node, paragraph::Paragraph};
+use std::fmt;
+
+/// The visual style of a [Badge](struct.Badge.html).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum BadgeStyle {
+    /// Flat with rounded corners.
+    #[default]
+    Flat,
+    /// Flat with square corners.
+    FlatSquare,
+    /// With a glossy gradient.
+    Plastic,
+    /// Large, with uppercase text.
+    ForTheBadge,
+    /// Styled like a social media button.
+    Social,
+}
+
+impl BadgeStyle {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Flat => "flat",
+            Self::FlatSquare => "flat-square",
+            Self::Plastic => "plastic",
+            Self::ForTheBadge => "for-the-badge",
+            Self::Social => "social",
+        }
+    }
+}
+
+/// A [shields.io](https://shields.io) badge, e.g. showing the CI status or
+/// the latest version of a crate.
+///
+/// Badges are rendered as an [Image](../image/struct.Image.html), linking to
+/// the [link](struct.Badge.html#structfield.link) if set.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Badge {
+    /// The text on the left side, may be empty.
+    pub label: String,
+    /// The text on the right side. If empty, the label is shown there
+    /// instead, as shields.io badges always have a message.
+    pub message: String,
+    /// The color of the right side, either a name like `blue` or a hex code.
+    pub color: String,
+    /// The visual style.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub style: BadgeStyle,
+    /// The name of a [Simple Icons](https://simpleicons.org) logo.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub logo: Option<String>,
+    /// The url the badge links to.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub link: Option<String>,
+    /// The path of a dynamic shields.io badge, e.g. `crates/v/serde`, used
+    /// instead of the label, message and color.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub path: Option<String>,
+}
+
+impl Badge {
+    /// Creates a new static `Badge`.
+    ///
+    /// # Arguments
+    ///
+    /// - `label`: The text on the left side, may be empty.
+    /// - `message`: The text on the right side.
+    /// - `color`: The color of the right side, e.g. `blue` or `#1e90ff`.
+    pub fn from(
+        label: impl Into<String>,
+        message: impl Into<String>,
+        color: impl Into<String>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            message: message.into(),
+            color: color.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a badge showing the latest version of a crate on crates.io.
+    pub fn crates_io(name: &str) -> Self {
+        Self {
+            label: "crates.io".into(),
+            link: Some(format!("https://crates.io/crates/{}", name)),
+            path: Some(format!("crates/v/{}", encode(name))),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a badge showing the documentation status of a crate on
+    /// docs.rs.
+    pub fn docs_rs(name: &str) -> Self {
+        Self {
+            label: "docs.rs".into(),
+            link: Some(format!("https://docs.rs/{}", name)),
+            path: Some(format!("docsrs/{}", encode(name))),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a badge showing the license of a crate on crates.io.
+    pub fn license(name: &str) -> Self {
+        Self {
+            label: "license".into(),
+            link: Some(format!("https://crates.io/crates/{}", name)),
+            path: Some(format!("crates/l/{}", encode(name))),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the visual style.
+    pub fn style(mut self, style: BadgeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the name of the [Simple Icons](https://simpleicons.org) logo.
+    pub fn logo(mut self, logo: impl Into<String>) -> Self {
+        self.logo = Some(logo.into());
+        self
+    }
+
+    /// Sets the url the badge links to.
+    pub fn linked_to(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+
+    /// Returns the url of the badge image.
+    pub fn url(&self) -> String {
+        let mut url = match &self.path {
+            Some(path) => format!("https://img.shields.io/{}", path),
+            None => {
+                let color = encode(self.color.trim_start_matches('#'));
+                match (self.label.is_empty(), self.message.is_empty()) {
+                    (false, false) => format!(
+                        "https://img.shields.io/badge/{}-{}-{}",
+                        encode_static(&self.label),
+                        encode_static(&self.message),
+                        color
+                    ),
+                    (false, true) => format!(
+                        "https://img.shields.io/badge/{}-{}",
+                        encode_static(&self.label),
+                        color
+                    ),
+                    (true, _) => format!(
+                        "https://img.shields.io/badge/{}-{}",
+                        encode_static(&self.message),
+                        color
+                    ),
+                }
+            }
+        };
+
+        let mut query = Vec::new();
+        if self.style != BadgeStyle::default() {
+            query.push(format!("style={}", self.style.name()));
+        }
+        if let Some(logo) = &self.logo {
+            query.push(format!("logo={}", encode(logo)));
+        }
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(&query.join("&"));
+        }
+
+        url
+    }
+
+    /// Returns the alternative text of the badge image.
+    fn alt_text(&self) -> String {
+        match (self.label.is_empty(), self.message.is_empty()) {
+            (false, false) => format!("{}: {}", self.label, self.message),
+            (false, true) => self.label.clone(),
+            (true, _) => self.message.clone(),
+        }
+    }
+}
+
+impl From<Badge> for Image {
+    fn from(badge: Badge) -> Self {
+        let mut image = Image::from(badge.url(), badge.alt_text(), false);
+        image.link = badge.link;
+        image
+    }
+}
+
+impl From<Badge> for Node {
+    fn from(badge: Badge) -> Self {
+        Self::Image(badge.into())
+    }
+}
+
+impl fmt::Display for Badge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let image: Image = self.clone().into();
+        image.fmt(f)
+    }
+}
+
+/// Badges rendered next to each other, as a paragraph of images.
+///
+/// Badges that do not fit on a line are wrapped like words, which markdown
+/// still shows on a single row.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BadgeRow {
+    /// The badges, from left to right.
+    pub badges: Vec<Badge>,
+}
+
+impl BadgeRow {
+    /// Creates a new `BadgeRow` with the given badges.
+    pub fn with(badges: Vec<Badge>) -> Self {
+        Self { badges }
+    }
+
+    /// Adds a badge to the end of the row.
+    pub fn add(&mut self, badge: Badge) -> &mut Self {
+        self.badges.push(badge);
+        self
+    }
+}
+
+impl From<BadgeRow> for Paragraph {
+    fn from(row: BadgeRow) -> Self {
+        let mut paragraph = Paragraph::new();
+        for (index, badge) in row.badges.into_iter().enumerate() {
+            if index != 0 {
+                paragraph.append(" ");
+            }
+            paragraph.image(badge.into());
+        }
+        paragraph
+    }
+}
+
+impl From<BadgeRow> for Node {
+    fn from(row: BadgeRow) -> Self {
+        Self::Paragraph(row.into())
+    }
+}
+
+impl fmt::Display for BadgeRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.badges.is_empty() {
+            Ok(())
+        } else {
+            let paragraph: Paragraph = self.clone().into();
+            paragraph.fmt(f)
+        }
+    }
+}
+
+/// Percent-encodes everything except unreserved URL characters.
+fn encode(text: &str) -> String {
+    let mut output = String::new();
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                output.push(byte as char)
+            }
+            _ => output.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    output
+}
+
+/// Encodes a part of a static badge path, where `-` separates the parts and
+/// `_` stands for a space.
+fn encode_static(text: &str) -> String {
+    let escaped: String = text
+        .chars()
+        .map(|c| match c {
+            '-' => "--".to_string(),
+            '_' => "__".to_string(),
+            ' ' => "_".to_string(),
+            c => c.to_string(),
+        })
+        .collect();
+    encode(&escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MarkdownElement;
+
+    #[test]
+    fn test_static_badge_url() {
+        let badge = Badge::from("build-status", "passing_ok 100%", "#4c1")
+            .style(BadgeStyle::ForTheBadge)
+            .logo("github actions");
+
+        assert_eq!(
+            badge.url(),
+            "https://img.shields.io/badge/build--status-passing__ok_100%25-4c1\
+            ?style=for-the-badge&logo=github%20actions"
+        );
+        assert_eq!(
+            Badge::from("", "v1", "blue").url(),
+            "https://img.shields.io/badge/v1-blue"
+        );
+        assert_eq!(
+            Badge::from("stable", "", "green").url(),
+            "https://img.shields.io/badge/stable-green"
+        );
+    }
+
+    #[test]
+    fn test_badge_render() {
+        let badge = Badge::from("license", "MIT", "blue").linked_to("LICENSE");
+
+        assert_eq!(
+            badge.render(),
+            "[![license: MIT](https://img.shields.io/badge/license-MIT-blue)](LICENSE)\n"
+        );
+    }
+
+    #[test]
+    fn test_presets_row() {
+        let row = BadgeRow::with(vec![
+            Badge::crates_io("markdown-builder"),
+            Badge::docs_rs("markdown-builder"),
+            Badge::license("markdown-builder"),
+        ]);
+
+        assert_eq!(
+            row.render(),
+            "[![crates.io](https://img.shields.io/crates/v/markdown-builder)](https://crates.io/crates/markdown-builder)\n\
+            [![docs.rs](https://img.shields.io/docsrs/markdown-builder)](https://docs.rs/markdown-builder)\n\
+            [![license](https://img.shields.io/crates/l/markdown-builder)](https://crates.io/crates/markdown-builder)\n"
+        );
+        assert_eq!(BadgeRow::default().render(), "");
+    }
+}
@@ -1,7 +1,10 @@
+use crate::{flavor::Flavor, types::list::List};
 use std::fmt;
 
 /// A checkbox list item.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Checkbox {
     Checked(String),
     Unchecked(String),
@@ -20,6 +23,18 @@ impl Checkbox {
             false => Checkbox::Unchecked(text.into()),
         }
     }
+
+    /// Returns whether the checkbox is checked.
+    pub fn is_checked(&self) -> bool {
+        matches!(self, Checkbox::Checked(_))
+    }
+
+    /// Returns the text next to the checkbox.
+    pub fn text(&self) -> &str {
+        match self {
+            Checkbox::Checked(text) | Checkbox::Unchecked(text) => text,
+        }
+    }
 }
 
 impl Default for Checkbox {
@@ -37,6 +52,55 @@ impl fmt::Display for Checkbox {
     }
 }
 
+/// A checkbox list item with nested subtasks.
+///
+/// The subtasks are rendered as a list below the checkbox, indented to the
+/// content of the enclosing list item.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Task {
+    /// The checkbox of the task itself.
+    pub checkbox: Checkbox,
+    /// The nested subtasks, usually checkboxes or other tasks.
+    pub subtasks: List,
+}
+
+impl Task {
+    /// Creates a new default task without subtasks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new task with the given values.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`: The text next to the checkbox.
+    /// - `checked`: Whether the checkbox is checked.
+    /// - `subtasks`: The nested list of subtasks.
+    pub fn from(text: impl Into<String>, checked: bool, subtasks: List) -> Self {
+        Self {
+            checkbox: Checkbox::from(text, checked),
+            subtasks,
+        }
+    }
+
+    /// Renders the task for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        if self.subtasks.items.is_empty() {
+            self.checkbox.to_string()
+        } else {
+            format!("{}\n{}", self.checkbox, self.subtasks.render_with(flavor))
+        }
+    }
+}
+
+impl fmt::Display for Task {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +141,19 @@ mod tests {
     fn test_checkbox_new() {
         assert_eq!(Checkbox::new(), Checkbox::default());
     }
+
+    #[test]
+    fn test_task_render() {
+        let task = Task::from(
+            "Deploy",
+            false,
+            List::builder()
+                .checkbox("Build", true)
+                .checkbox("Upload", false)
+                .ordered(),
+        );
+
+        assert_eq!(task.render(), "[ ] Deploy\n1. [x] Build\n2. [ ] Upload\n");
+        assert_eq!(Task::from("Done", true, List::new()).render(), "[x] Done");
+    }
 }
@@ -0,0 +1,320 @@
+//! Contains the CSV importer of [Table](../table/struct.Table.html).
+
+use crate::types::table::{Alignment, Table};
+use std::{fmt, io};
+
+/// The options used by [Table::from_csv](../table/struct.Table.html#method.from_csv).
+#[derive(Clone, Debug)]
+pub struct CsvOptions {
+    headers: bool,
+    delimiter: u8,
+    max_width: Option<usize>,
+    detect_numeric: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            headers: true,
+            delimiter: b',',
+            max_width: None,
+            detect_numeric: true,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Creates new default options.
+    ///
+    /// The first record is used as the header row, fields are separated by
+    /// commas and numeric columns are right aligned.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the first record is the header row.
+    ///
+    /// When disabled, the first record is a regular row and the headers are
+    /// left empty.
+    pub fn set_headers(mut self, value: bool) -> Self {
+        self.headers = value;
+        self
+    }
+
+    /// Sets the delimiter separating the fields.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Truncates cells longer than `width` characters, ending them with an
+    /// ellipsis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero.
+    pub fn max_width(mut self, width: usize) -> Self {
+        assert!(width > 0, "the max width has to be at least one");
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets whether columns only containing numbers are right aligned.
+    pub fn set_detect_numeric(mut self, value: bool) -> Self {
+        self.detect_numeric = value;
+        self
+    }
+}
+
+/// An error that occurred while importing a CSV file.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The input does not contain any record.
+    Empty,
+    /// A record has a different number of fields than the first one.
+    UnequalLengths {
+        /// The line of the record, starting at one.
+        line: u64,
+        /// The number of fields of the first record.
+        expected: u64,
+        /// The number of fields of the record.
+        found: u64,
+    },
+    /// A record is not valid UTF-8.
+    InvalidUtf8 {
+        /// The line of the record, starting at one.
+        line: u64,
+    },
+    /// Reading the input failed.
+    Io(io::Error),
+    /// The csv parser reported any other error.
+    Parse(csv::Error),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the csv input is empty"),
+            Self::UnequalLengths {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "record on line {} has {} fields, expected {}",
+                line, found, expected
+            ),
+            Self::InvalidUtf8 { line } => write!(f, "record on line {} is not valid UTF-8", line),
+            Self::Io(error) => write!(f, "failed to read the csv input: {}", error),
+            Self::Parse(error) => write!(f, "failed to parse the csv input: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<csv::Error> for CsvError {
+    fn from(error: csv::Error) -> Self {
+        let line = |position: Option<&csv::Position>| position.map_or(0, csv::Position::line);
+
+        match error.kind() {
+            csv::ErrorKind::Io(_) => match error.into_kind() {
+                csv::ErrorKind::Io(error) => Self::Io(error),
+                _ => unreachable!("the kind was checked to be an io error"),
+            },
+            csv::ErrorKind::Utf8 { pos, .. } => Self::InvalidUtf8 {
+                line: line(pos.as_ref()),
+            },
+            csv::ErrorKind::UnequalLengths {
+                pos,
+                expected_len,
+                len,
+            } => Self::UnequalLengths {
+                line: line(pos.as_ref()),
+                expected: *expected_len,
+                found: *len,
+            },
+            _ => Self::Parse(error),
+        }
+    }
+}
+
+/// Truncates the cell to `width` characters, replacing the last one by an
+/// ellipsis.
+fn truncate(cell: &str, width: Option<usize>) -> String {
+    match width {
+        Some(width) if cell.chars().count() > width => {
+            let mut truncated: String = cell.chars().take(width - 1).collect();
+            truncated.push('…');
+            truncated
+        }
+        _ => cell.to_string(),
+    }
+}
+
+/// Returns whether the cell is a number, e.g. `42`, `-1.5` or `1e3`.
+fn is_numeric(cell: &str) -> bool {
+    let cell = cell.trim();
+    !cell.is_empty() && cell.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+impl Table {
+    /// Creates a new `Table` from CSV data.
+    ///
+    /// Quoted fields may contain delimiters and line breaks, line breaks are
+    /// rendered as `<br>`. Columns whose cells are all numbers are right
+    /// aligned, unless turned off in the `options`.
+    ///
+    /// # Arguments
+    ///
+    /// - `reader`: The CSV data.
+    /// - `options`: The options to use while importing.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is empty, cannot be read, is not valid UTF-8 or if
+    /// the records have a different number of fields.
+    pub fn from_csv(reader: impl io::Read, options: CsvOptions) -> Result<Self, CsvError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .delimiter(options.delimiter)
+            .from_reader(reader);
+
+        let mut records = Vec::new();
+        for record in reader.records() {
+            let record = record?;
+            records.push(
+                record
+                    .iter()
+                    .map(|cell| truncate(cell, options.max_width))
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        if records.is_empty() {
+            return Err(CsvError::Empty);
+        }
+
+        let mut table = if options.headers {
+            let headers = records.remove(0);
+            Table::from(headers)
+        } else {
+            Table::from(vec![String::new(); records[0].len()])
+        };
+        table.rows = records;
+
+        if options.detect_numeric {
+            for column in 0..table.headers.len() {
+                let mut cells = table.rows.iter().map(|row| row[column].as_str());
+                if cells.clone().any(is_numeric)
+                    && cells.all(|cell| cell.trim().is_empty() || is_numeric(cell))
+                {
+                    table.align(column, Alignment::Right);
+                }
+            }
+        }
+
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MarkdownElement;
+
+    #[test]
+    fn test_from_csv() {
+        let csv = "name,downloads\nserde,300\nsyn,25\n";
+        let table = Table::from_csv(csv.as_bytes(), CsvOptions::new()).unwrap();
+
+        assert_eq!(
+            table.render(),
+            "| name  | downloads |\n\
+            | ----- | --------: |\n\
+            | serde |       300 |\n\
+            | syn   |        25 |\n"
+        );
+    }
+
+    #[test]
+    fn test_quoted_fields_and_newlines() {
+        let csv = "text,note\n\"a, b\",\"line\nbreak\"\n";
+        let table = Table::from_csv(csv.as_bytes(), CsvOptions::new()).unwrap();
+
+        assert_eq!(table.rows, vec![vec!["a, b", "line\nbreak"]]);
+        assert!(table.render().contains("| a, b | line<br>break |"));
+    }
+
+    #[test]
+    fn test_numeric_detection() {
+        let csv = "a,b,c\n1.5,x,\n-2,3,\n,4,\n";
+        let table = Table::from_csv(csv.as_bytes(), CsvOptions::new()).unwrap();
+
+        assert_eq!(table.alignment(0), Alignment::Right);
+        assert_eq!(table.alignment(1), Alignment::None);
+        assert_eq!(table.alignment(2), Alignment::None);
+
+        let table =
+            Table::from_csv(csv.as_bytes(), CsvOptions::new().set_detect_numeric(false)).unwrap();
+        assert_eq!(table.alignment(0), Alignment::None);
+    }
+
+    #[test]
+    fn test_max_width() {
+        let csv = "name\nmarkdown-builder\n";
+        let table = Table::from_csv(csv.as_bytes(), CsvOptions::new().max_width(8)).unwrap();
+
+        assert_eq!(table.rows, vec![vec!["markdow…"]]);
+    }
+
+    #[test]
+    fn test_options() {
+        let csv = "1;2\n3;4\n";
+        let table = Table::from_csv(
+            csv.as_bytes(),
+            CsvOptions::new().set_headers(false).delimiter(b';'),
+        )
+        .unwrap();
+
+        assert_eq!(table.headers, vec!["", ""]);
+        assert_eq!(table.rows.len(), 2);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            Table::from_csv("".as_bytes(), CsvOptions::new()),
+            Err(CsvError::Empty)
+        ));
+        assert!(matches!(
+            Table::from_csv("a,b\n1\n".as_bytes(), CsvOptions::new()),
+            Err(CsvError::UnequalLengths {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(matches!(
+            Table::from_csv(&b"a\n\xff\n"[..], CsvOptions::new()),
+            Err(CsvError::InvalidUtf8 { line: 2 })
+        ));
+
+        let error = csv::Reader::from_reader("n\nx\n".as_bytes())
+            .deserialize::<u32>()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        let error = CsvError::from(error);
+        assert!(matches!(error, CsvError::Parse(_)));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    flavor::Flavor,
+    html,
+    transforms::Bold,
+    types::{node::Node, paragraph::Paragraph},
+};
+use std::fmt;
+
+/// How a [DefinitionList](struct.DefinitionList.html) is rendered in flavors
+/// without native support.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum DefinitionFallback {
+    /// Renders the term in bold, followed by its definitions.
+    #[default]
+    Bold,
+    /// Renders an HTML `<dl>` element.
+    Html,
+}
+
+/// A term along with its definitions.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionItem {
+    /// The defined term.
+    pub term: String,
+    /// The definitions, each consisting of one or more blocks.
+    pub definitions: Vec<Vec<Node>>,
+}
+
+impl DefinitionItem {
+    /// Creates a new `DefinitionItem` without definitions.
+    pub fn new(term: impl Into<String>) -> Self {
+        Self {
+            term: term.into(),
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Adds a definition consisting of a single paragraph.
+    pub fn definition(&mut self, text: impl Into<String>) -> &mut Self {
+        self.definitions
+            .push(vec![Node::Paragraph(Paragraph::from(text))]);
+        self
+    }
+
+    /// Adds a definition consisting of the given blocks.
+    pub fn definition_with(&mut self, children: Vec<impl Into<Node>>) -> &mut Self {
+        self.definitions
+            .push(children.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// A definition list, e.g. a glossary or the flags of a command.
+///
+/// Rendered as `Term\n:   Definition` in flavors supporting it, and using the
+/// [fallback](struct.DefinitionList.html#structfield.fallback) otherwise.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionList {
+    /// The terms and their definitions.
+    pub items: Vec<DefinitionItem>,
+    /// The rendering used in flavors without definition lists.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fallback: DefinitionFallback,
+}
+
+impl DefinitionList {
+    /// Creates a new empty `DefinitionList`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `DefinitionList` with the given items.
+    pub fn with(items: Vec<DefinitionItem>) -> Self {
+        Self {
+            items,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a term with one single paragraph definition per text.
+    ///
+    /// # Arguments
+    ///
+    /// - `term`: The defined term.
+    /// - `definitions`: The definitions of the term.
+    pub fn term(
+        &mut self,
+        term: impl Into<String>,
+        definitions: Vec<impl Into<String>>,
+    ) -> &mut Self {
+        let mut item = DefinitionItem::new(term);
+        for definition in definitions {
+            item.definition(definition);
+        }
+        self.items.push(item);
+        self
+    }
+
+    /// Adds an item to the list.
+    pub fn add(&mut self, item: DefinitionItem) -> &mut Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Sets the rendering used in flavors without definition lists.
+    pub fn set_fallback(&mut self, fallback: DefinitionFallback) -> &mut Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Renders the list for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        let blocks = |definition: &[Node]| {
+            definition
+                .iter()
+                .map(|node| node.render_with(flavor).trim_end_matches('\n').to_string())
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        };
+
+        let items: Vec<String> = self
+            .items
+            .iter()
+            .map(|item| {
+                if flavor.supports_definition_lists() {
+                    let mut lines = vec![item.term.clone()];
+                    for definition in &item.definitions {
+                        for (index, line) in blocks(definition).lines().enumerate() {
+                            lines.push(match (index, line.is_empty()) {
+                                (0, _) => format!(":   {}", line),
+                                (_, true) => String::new(),
+                                (_, false) => format!("    {}", line),
+                            });
+                        }
+                    }
+                    lines.join("\n")
+                } else if self.fallback == DefinitionFallback::Html {
+                    let mut lines = vec![format!("<dt>{}</dt>", html::escape(&item.term))];
+                    for definition in &item.definitions {
+                        lines.push(format!("<dd>\n\n{}\n\n</dd>", blocks(definition)));
+                    }
+                    lines.join("\n")
+                } else {
+                    std::iter::once(item.term.to_bold())
+                        .chain(item.definitions.iter().map(|definition| blocks(definition)))
+                        .collect::<Vec<_>>()
+                        .join("\n\n")
+                }
+            })
+            .collect();
+
+        if items.is_empty() {
+            String::new()
+        } else if !flavor.supports_definition_lists() && self.fallback == DefinitionFallback::Html {
+            format!("<dl>\n{}\n</dl>\n", items.join("\n"))
+        } else {
+            format!("{}\n", items.join("\n\n"))
+        }
+    }
+}
+
+impl fmt::Display for DefinitionList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ListBuilder, MarkdownElement};
+
+    fn flags() -> DefinitionList {
+        let mut verbose = DefinitionItem::new("--verbose");
+        verbose.definition_with(vec![
+            Node::from(Paragraph::from("Prints more output.")),
+            Node::from(
+                ListBuilder::new()
+                    .append("info")
+                    .append("debug")
+                    .unordered(),
+            ),
+        ]);
+
+        let mut list = DefinitionList::new();
+        list.term("--quiet", vec!["Prints nothing.", "Alias: -q"])
+            .add(verbose);
+        list
+    }
+
+    #[test]
+    fn test_native_definition_list() {
+        assert_eq!(
+            flags().render_with(Flavor::Pandoc),
+            "--quiet\n:   Prints nothing.\n:   Alias: -q\n\n\
+            --verbose\n:   Prints more output.\n\n    - info\n    - debug\n"
+        );
+    }
+
+    #[test]
+    fn test_bold_fallback() {
+        assert_eq!(
+            flags().render(),
+            "**--quiet**\n\nPrints nothing.\n\nAlias: -q\n\n\
+            **--verbose**\n\nPrints more output.\n\n- info\n- debug\n"
+        );
+    }
+
+    #[test]
+    fn test_html_fallback() {
+        let mut list = DefinitionList::new();
+        list.term("<T>", vec!["A type."])
+            .set_fallback(DefinitionFallback::Html);
+
+        assert_eq!(
+            list.render_with(Flavor::GitHub),
+            "<dl>\n<dt>&lt;T&gt;</dt>\n<dd>\n\nA type.\n\n</dd>\n</dl>\n"
+        );
+        assert_eq!(
+            list.render_with(Flavor::MarkdownExtra),
+            "<T>\n:   A type.\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_definition_list() {
+        assert_eq!(DefinitionList::new().render(), "");
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{
+    flavor::Flavor,
+    html,
+    traits::MarkdownElement,
+    types::{
+        image::Image, link::Link, list::List, markdown::join_blocks, node::Node,
+        paragraph::Paragraph, quote::Quote,
+    },
+};
+use std::fmt;
+
+/// A collapsible `<details>` element, e.g. for long logs.
+///
+/// The children are regular markdown elements. They are surrounded by blank
+/// lines, which are required for markdown inside of HTML blocks to be rendered.
+///
+/// Details are usually created using
+/// [Markdown::details](../markdown/struct.Markdown.html#method.details).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Details {
+    /// The always visible summary, as plain text. HTML special characters are
+    /// escaped.
+    pub summary: String,
+    /// The elements shown when the details are expanded.
+    pub children: Vec<Node>,
+    /// Whether the details are expanded by default.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub open: bool,
+}
+
+impl Details {
+    /// Creates a new collapsed `Details` without children.
+    pub fn new(summary: impl Into<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new collapsed `Details` with the given children.
+    pub fn with(summary: impl Into<String>, children: Vec<impl Into<Node>>) -> Self {
+        Self {
+            summary: summary.into(),
+            children: children.into_iter().map(Into::into).collect(),
+            open: false,
+        }
+    }
+
+    /// Sets whether the details are expanded by default.
+    pub fn set_open(&mut self, open: bool) -> &mut Self {
+        self.open = open;
+        self
+    }
+
+    /// Adds nested details.
+    ///
+    /// # Arguments
+    ///
+    /// - `summary`: The nested details' summary.
+    /// - `build`: Called with the nested details to add their content.
+    pub fn details(
+        &mut self,
+        summary: impl Into<String>,
+        build: impl FnOnce(&mut Details),
+    ) -> &mut Self {
+        let mut details = Details::new(summary);
+        build(&mut details);
+        self.children.push(Node::Details(details));
+        self
+    }
+
+    /// Adds any generic markdown element to the details.
+    pub fn add(&mut self, element: impl MarkdownElement + 'static) -> &mut Self {
+        self.children.push(Node::from_element(element));
+        self
+    }
+
+    /// Adds a paragraph to the details.
+    pub fn paragraph(&mut self, text: impl Into<String>) -> &mut Self {
+        self.children.push(Node::Paragraph(Paragraph::from(text)));
+        self
+    }
+
+    /// Adds a list to the details.
+    pub fn list(&mut self, list: List) -> &mut Self {
+        self.children.push(Node::List(list));
+        self
+    }
+
+    /// Adds a link to the details.
+    pub fn link(&mut self, link: Link) -> &mut Self {
+        self.children.push(Node::Link(link));
+        self
+    }
+
+    /// Adds an image to the details.
+    pub fn image(&mut self, image: Image) -> &mut Self {
+        self.children.push(Node::Image(image));
+        self
+    }
+
+    /// Adds a block quote to the details.
+    pub fn quote(&mut self, quote: Quote) -> &mut Self {
+        self.children.push(Node::Quote(quote));
+        self
+    }
+
+    /// Renders the details for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        self.render_blocks(flavor, false)
+    }
+
+    /// Renders the details, separating their children like a compact document
+    /// if `compact` is set. The blank lines around the children are kept.
+    pub(crate) fn render_blocks(&self, flavor: Flavor, compact: bool) -> String {
+        let mut output = if self.open {
+            "<details open>\n".to_string()
+        } else {
+            "<details>\n".to_string()
+        };
+        output.push_str(&format!(
+            "<summary>{}</summary>\n",
+            html::escape(&self.summary)
+        ));
+
+        let children = join_blocks(
+            self.children
+                .iter()
+                .map(|child| child.render_block(flavor, compact)),
+            compact,
+        );
+        if !children.is_empty() {
+            output.push('\n');
+            output.push_str(&children);
+            output.push('\n');
+        }
+        output.push_str("</details>\n");
+        output
+    }
+}
+
+impl fmt::Display for Details {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeBlock, ListBuilder};
+
+    #[test]
+    fn test_details() {
+        let mut details = Details::new("Build log");
+        details
+            .paragraph("Exit code 1")
+            .add("error: linking failed".to_code_block());
+
+        assert_eq!(
+            details.render(),
+            "<details>\n<summary>Build log</summary>\n\n\
+            Exit code 1\n\n\
+            ```\nerror: linking failed\n```\n\n\
+            </details>\n"
+        );
+    }
+
+    #[test]
+    fn test_open_and_empty_details() {
+        let mut details = Details::new("Nothing to see");
+        details.set_open(true);
+
+        assert_eq!(
+            details.render(),
+            "<details open>\n<summary>Nothing to see</summary>\n</details>\n"
+        );
+    }
+
+    #[test]
+    fn test_summary_is_escaped() {
+        assert_eq!(
+            Details::new("a < b && c").render(),
+            "<details>\n<summary>a &lt; b &amp;&amp; c</summary>\n</details>\n"
+        );
+    }
+
+    #[test]
+    fn test_details_inside_list() {
+        let list = ListBuilder::new()
+            .append(Details::with("Logs", vec![Paragraph::from("ok")]))
+            .append("next")
+            .unordered();
+
+        assert_eq!(
+            list.render(),
+            "- <details>\n  <summary>Logs</summary>\n\n  ok\n\n  </details>\n- next\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_details() {
+        let mut details = Details::new("Outer");
+        details.details("Inner", |inner| {
+            inner.paragraph("Hello");
+        });
+
+        assert_eq!(
+            details.render(),
+            "<details>\n<summary>Outer</summary>\n\n\
+            <details>\n<summary>Inner</summary>\n\nHello\n\n</details>\n\n\
+            </details>\n"
+        );
+    }
+}
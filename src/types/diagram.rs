@@ -0,0 +1,67 @@
+use crate::transforms::CodeBlock;
+use std::fmt;
+
+/// A diagram rendered from its source by the markdown viewer, e.g. using
+/// Mermaid.
+///
+/// Mermaid flowcharts and sequence diagrams can be created using the typed
+/// [builders](../../builders/diagram/index.html), other diagram types such as
+/// PlantUML or Graphviz are created from their raw source.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagram {
+    /// The language of the source, used as the info string of the code block.
+    pub language: String,
+    /// The source of the diagram.
+    pub source: String,
+}
+
+impl Diagram {
+    /// Creates a new `Diagram` from source in the given language.
+    ///
+    /// # Arguments
+    ///
+    /// - `language`: The diagram language, e.g. `plantuml` or `dot`.
+    /// - `source`: The source of the diagram.
+    pub fn from(language: impl Into<String>, source: impl Into<String>) -> Self {
+        Self {
+            language: language.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Creates a new Mermaid `Diagram` from its raw source.
+    pub fn mermaid(source: impl Into<String>) -> Self {
+        Self::from("mermaid", source)
+    }
+}
+
+impl fmt::Display for Diagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}",
+            self.source
+                .trim_end_matches('\n')
+                .to_code_block_with_language(&self.language)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MarkdownElement;
+
+    #[test]
+    fn test_raw_diagrams() {
+        assert_eq!(
+            Diagram::mermaid("pie\n    \"Rust\" : 100\n").render(),
+            "```mermaid\npie\n    \"Rust\" : 100\n```\n"
+        );
+        assert_eq!(
+            Diagram::from("dot", "digraph { a -> b }").render(),
+            "```dot\ndigraph { a -> b }\n```\n"
+        );
+    }
+}
@@ -1,8 +1,10 @@
+use crate::{flavor::Flavor, types::paragraph::escape_dollars};
 use std::fmt;
 use tousize::ToUsize;
 
 /// The level of a header.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct HeaderLevel(usize);
 
 impl Default for HeaderLevel {
@@ -26,6 +28,51 @@ impl HeaderLevel {
         assert!((1..=6).contains(&level));
         Self(level)
     }
+
+    /// Creates a new header level, returning `None` if the level is not valid
+    /// (one to six inclusive).
+    pub fn checked(level: impl ToUsize) -> Option<Self> {
+        let level = level.to_usize();
+        (1..=6).contains(&level).then_some(Self(level))
+    }
+
+    /// Returns the numeric value of the header level.
+    pub fn value(&self) -> usize {
+        self.0
+    }
+
+    /// Returns the level shifted by `delta`, or `None` if the shifted level is
+    /// not valid.
+    ///
+    /// A positive `delta` makes the header less important.
+    pub fn shifted(&self, delta: isize) -> Option<Self> {
+        let level = self.0 as isize + delta;
+        (1..=6).contains(&level).then_some(Self(level as usize))
+    }
+
+    /// Returns the level shifted by `delta`, clamped to the valid levels.
+    pub fn shifted_clamped(&self, delta: isize) -> Self {
+        Self((self.0 as isize + delta).clamp(1, 6) as usize)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HeaderLevel {
+    /// Deserializes a header level, failing if it is not valid (one to six
+    /// inclusive).
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let level = usize::deserialize(deserializer)?;
+        if !(1..=6).contains(&level) {
+            return Err(serde::de::Error::custom(format!(
+                "invalid header level {}, expected one to six",
+                level
+            )));
+        }
+        Ok(Self(level))
+    }
 }
 
 impl<T> From<T> for HeaderLevel
@@ -37,8 +84,46 @@ where
     }
 }
 
+/// Decides what happens to headers whose level would leave the valid range when
+/// shifting headers.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum HeaderOverflow {
+    /// Clamps the level to the valid range (one to six inclusive).
+    #[default]
+    Clamp,
+    /// Replaces the header with a paragraph containing its text in bold.
+    ///
+    /// Sections are replaced by their title in bold followed by their content.
+    Demote,
+    /// Fails with a [HeaderLevelError](struct.HeaderLevelError.html) without
+    /// modifying anything.
+    Error,
+}
+
+/// The error returned when a header would be shifted to an invalid level.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeaderLevelError {
+    /// The text of the offending header.
+    pub text: String,
+    /// The invalid level the header would have been shifted to.
+    pub level: isize,
+}
+
+impl fmt::Display for HeaderLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "header \"{}\" would be shifted to level {}, expected one to six",
+            self.text, self.level
+        )
+    }
+}
+
+impl std::error::Error for HeaderLevelError {}
+
 /// A markdown header.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     /// The header text.
     pub text: String,
@@ -62,18 +147,32 @@ impl Header {
             level: level.into(),
         }
     }
+
+    /// Renders the header for the given flavor.
+    ///
+    /// Dollar signs are escaped in flavors rendering math, like in
+    /// paragraphs.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        let text = if flavor.supports_math() {
+            escape_dollars(&self.text)
+        } else {
+            self.text.clone()
+        };
+
+        format!("{} {}\n", "#".repeat(self.level.0), text)
+    }
 }
 
 impl fmt::Display for Header {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{} {}", "#".repeat(self.level.0), self.text)
+        f.write_str(&self.render_with(Flavor::default()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Header, HeaderLevel};
-    use crate::MarkdownElement;
+    use crate::{Flavor, MarkdownElement};
 
     #[test]
     fn test_header_level() {
@@ -92,6 +191,32 @@ mod tests {
         HeaderLevel::from(7usize);
     }
 
+    #[test]
+    fn test_header_level_checked() {
+        assert_eq!(
+            HeaderLevel::checked(3usize),
+            Some(HeaderLevel::from(3usize))
+        );
+        assert_eq!(HeaderLevel::checked(0usize), None);
+        assert_eq!(HeaderLevel::checked(7usize), None);
+    }
+
+    #[test]
+    fn test_header_level_shifted() {
+        let level = HeaderLevel::from(2usize);
+        assert_eq!(level.shifted(1), Some(HeaderLevel::from(3usize)));
+        assert_eq!(level.shifted(-1), Some(HeaderLevel::from(1usize)));
+        assert_eq!(level.shifted(-2), None);
+        assert_eq!(level.shifted(5), None);
+    }
+
+    #[test]
+    fn test_header_level_shifted_clamped() {
+        let level = HeaderLevel::from(2usize);
+        assert_eq!(level.shifted_clamped(10), HeaderLevel::from(6usize));
+        assert_eq!(level.shifted_clamped(-10), HeaderLevel::from(1usize));
+    }
+
     #[test]
     fn test_header_of_all_sizes() {
         assert_eq!(Header::from("A header", 1usize).render(), "# A header\n");
@@ -113,4 +238,15 @@ mod tests {
         assert_eq!(Header::new().level, 1usize.into());
         assert_eq!(Header::new().text, "");
     }
+
+    #[test]
+    fn test_header_dollars_escaped_for_math_flavors() {
+        let header = Header::from("Pricing in $USD", 2usize);
+
+        assert_eq!(header.render_with(Flavor::GitHub), "## Pricing in \\$USD\n");
+        assert_eq!(
+            header.render_with(Flavor::CommonMark),
+            "## Pricing in $USD\n"
+        );
+    }
 }
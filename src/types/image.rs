@@ -1,8 +1,20 @@
-use crate::traits::{AsFooter, MarkdownElement};
+use crate::{
+    flavor::Flavor,
+    html,
+    traits::{AsFooter, MarkdownElement},
+    types::{link::destination, table::Alignment},
+};
 use std::fmt;
 
 /// A markdown image.
+///
+/// Images with an [alignment](struct.Image.html#structfield.align) or a size
+/// are rendered as an HTML `<img>`, as markdown has no syntax for them. In
+/// flavors [supporting attributes](../../flavor/enum.Flavor.html#method.supports_image_attributes)
+/// the size is written as `{width=... height=...}` instead. Footer references
+/// are only used for images rendered as markdown.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Image {
     /// Whether the image's link should be added as a footer reference.
     pub footer: bool,
@@ -10,6 +22,49 @@ pub struct Image {
     pub text: String,
     /// The url of the image.
     pub url: String,
+    /// The label of the footer reference, defaults to the text.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub label: Option<String>,
+    /// The title of the image, usually shown as a tooltip.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub title: Option<String>,
+    /// The width, e.g. `200` pixels or `50%`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub width: Option<String>,
+    /// The height, e.g. `200` pixels or `50%`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub height: Option<String>,
+    /// The horizontal alignment of the image.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Alignment::is_none")
+    )]
+    pub align: Alignment,
+    /// The url the image links to.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub link: Option<String>,
+    /// The label of the link's footer reference, defaults to the image's
+    /// label followed by `-link`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub link_label: Option<String>,
 }
 
 impl Image {
@@ -24,23 +79,128 @@ impl Image {
             text: text.into(),
             url: url.into(),
             footer,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the label used to reference the footer.
+    pub fn footer_label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.text)
+    }
+
+    /// Returns the label used to reference the footer of the link.
+    pub fn link_footer_label(&self) -> String {
+        self.link_label
+            .clone()
+            .unwrap_or_else(|| format!("{}-link", self.footer_label()))
+    }
+
+    /// Returns whether the image is rendered as HTML in the given flavor.
+    pub fn is_html(&self, flavor: Flavor) -> bool {
+        let sized = self.width.is_some() || self.height.is_some();
+        !self.align.is_none() || (sized && !flavor.supports_image_attributes())
+    }
+
+    /// Returns the footers of the image and its link when rendered in the
+    /// given flavor.
+    pub fn footers(&self, flavor: Flavor) -> Vec<String> {
+        if !self.footer || self.is_html(flavor) {
+            return Vec::new();
+        }
+
+        let mut footers = vec![self.as_footer().render()];
+        if let Some(link) = &self.link {
+            footers.push(format!(
+                "[{}]: {}",
+                self.link_footer_label(),
+                destination(link, None)
+            ));
+        }
+        footers
+    }
+
+    /// Renders the image for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        if self.is_html(flavor) {
+            return format!("{}\n", self.render_html());
+        }
+
+        let mut image = if self.footer {
+            format!("![{}][{}]", self.text, self.footer_label())
+        } else {
+            format!(
+                "![{}]({})",
+                self.text,
+                destination(&self.url, self.title.as_deref())
+            )
+        };
+
+        let attributes: Vec<String> = [("width", &self.width), ("height", &self.height)]
+            .iter()
+            .filter_map(|(name, value)| value.as_ref().map(|value| format!("{}={}", name, value)))
+            .collect();
+        if !attributes.is_empty() {
+            image.push_str(&format!("{{{}}}", attributes.join(" ")));
+        }
+
+        match &self.link {
+            Some(_) if self.footer => format!("[{}][{}]\n", image, self.link_footer_label()),
+            Some(link) => format!("[{}]({})\n", image, destination(link, None)),
+            None => format!("{}\n", image),
+        }
+    }
+
+    fn render_html(&self) -> String {
+        let mut image = format!(
+            "<img src=\"{}\" alt=\"{}\"",
+            html::escape(&self.url),
+            html::escape(&self.text)
+        );
+        let attributes = [
+            ("title", self.title.as_deref()),
+            ("width", self.width.as_deref()),
+            ("height", self.height.as_deref()),
+            (
+                "align",
+                match self.align {
+                    Alignment::Left => Some("left"),
+                    Alignment::Right => Some("right"),
+                    Alignment::None | Alignment::Center => None,
+                },
+            ),
+        ];
+        for (name, value) in attributes {
+            if let Some(value) = value {
+                image.push_str(&format!(" {}=\"{}\"", name, html::escape(value)));
+            }
+        }
+        image.push('>');
+
+        if let Some(link) = &self.link {
+            image = format!("<a href=\"{}\">{}</a>", html::escape(link), image);
+        }
+
+        if self.align == Alignment::Center {
+            format!("<p align=\"center\">\n{}\n</p>", image)
+        } else {
+            image
         }
     }
 }
 
 impl AsFooter for Image {
     fn as_footer(&self) -> Box<dyn MarkdownElement> {
-        Box::new(format!("[{}]: {}", self.text, self.url))
+        Box::new(format!(
+            "[{}]: {}",
+            self.footer_label(),
+            destination(&self.url, self.title.as_deref())
+        ))
     }
 }
 
 impl fmt::Display for Image {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.footer {
-            writeln!(f, "![{}][{}]", self.text, self.text)
-        } else {
-            writeln!(f, "![{}]({})", self.text, self.url)
-        }
+        f.write_str(&self.render_with(Flavor::default()))
     }
 }
 
@@ -51,7 +211,7 @@ mod tests {
     #[test]
     fn test_image_default() {
         let image = Image::new();
-        assert_eq!(image.footer, false);
+        assert!(!image.footer);
         assert_eq!(image.text, "");
         assert_eq!(image.url, "");
     }
@@ -63,7 +223,7 @@ mod tests {
             "A cute image of a sandcat",
             true,
         );
-        assert_eq!(image.footer, true);
+        assert!(image.footer);
         assert_eq!(image.text, "A cute image of a sandcat");
         assert_eq!(image.url, "https://example.com/picture.png");
     }
@@ -105,4 +265,65 @@ mod tests {
             "[A cute picture of a sandcat]: https://example.com/picture.png"
         )
     }
+
+    #[test]
+    fn test_sized_and_aligned_image() {
+        let mut image = Image::from("logo.png", "Logo \"v2\"", false);
+        image.width = Some("200".into());
+
+        assert_eq!(
+            image.render(),
+            "<img src=\"logo.png\" alt=\"Logo &quot;v2&quot;\" width=\"200\">\n"
+        );
+        assert_eq!(
+            image.render_with(Flavor::Pandoc),
+            "![Logo \"v2\"](logo.png){width=200}\n"
+        );
+
+        image.align = Alignment::Center;
+        image.link = Some("https://example.com".into());
+        assert_eq!(
+            image.render_with(Flavor::Pandoc),
+            "<p align=\"center\">\n\
+            <a href=\"https://example.com\"><img src=\"logo.png\" alt=\"Logo &quot;v2&quot;\" width=\"200\"></a>\n\
+            </p>\n"
+        );
+    }
+
+    #[test]
+    fn test_linked_image() {
+        let mut image = Image::from("shot.png", "Screenshot", false);
+        image.link = Some("full size.png".into());
+        assert_eq!(
+            image.render(),
+            "[![Screenshot](shot.png)](<full size.png>)\n"
+        );
+
+        image.footer = true;
+        assert_eq!(
+            image.render(),
+            "[![Screenshot][Screenshot]][Screenshot-link]\n"
+        );
+        assert_eq!(
+            image.footers(Flavor::default()),
+            vec![
+                "[Screenshot]: shot.png",
+                "[Screenshot-link]: <full size.png>"
+            ]
+        );
+
+        image.width = Some("50%".into());
+        assert!(image.footers(Flavor::default()).is_empty());
+    }
+
+    #[test]
+    fn test_image_title_and_escaped_url() {
+        let mut image = Image::from("images/sand cat.png", "Sandcat", false);
+        image.title = Some("A \"cute\" sandcat".into());
+
+        assert_eq!(
+            image.render(),
+            "![Sandcat](<images/sand cat.png> \"A \\\"cute\\\" sandcat\")\n"
+        );
+    }
 }
@@ -0,0 +1,71 @@
+use crate::{flavor::Flavor, types::markdown::Markdown};
+use std::fmt;
+
+/// A sub-document embedded in place.
+///
+/// Only the elements of the document are rendered. Its footers, including the
+/// ones of its links and images, are rendered by the document containing the
+/// `Include`, together with its own footers.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Include {
+    /// The included document.
+    pub document: Markdown,
+}
+
+impl Include {
+    /// Creates a new `Include` of an empty document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `Include` of the given document.
+    pub fn from(document: Markdown) -> Self {
+        Self { document }
+    }
+
+    /// Renders the elements of the included document for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        self.render_blocks(flavor, self.document.compact)
+    }
+
+    /// Renders the elements, separating them like a compact document if
+    /// `compact` is set instead of following the included document.
+    pub(crate) fn render_blocks(&self, flavor: Flavor, compact: bool) -> String {
+        self.document.render_elements(flavor, compact)
+    }
+}
+
+impl fmt::Display for Include {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LinkBuilder, MarkdownElement};
+
+    #[test]
+    fn test_include_renders_elements() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").paragraph("Text");
+
+        assert_eq!(Include::from(doc).render(), "# Title\n\nText\n");
+    }
+
+    #[test]
+    fn test_include_omits_footers() {
+        let mut doc = Markdown::new();
+        doc.link(
+            LinkBuilder::new()
+                .url("https://example.com")
+                .text("Example")
+                .footer()
+                .build(),
+        );
+
+        assert_eq!(Include::from(doc).render(), "[Example][Example]\n");
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt;
 
 /// A markdown link.
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Link {
     /// The url of the link.
     pub url: String,
@@ -12,6 +13,18 @@ pub struct Link {
     pub footer: bool,
     /// Whether the link should be inlined (no new line).
     pub inlined: bool,
+    /// The label of the footer reference, defaults to the text.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub label: Option<String>,
+    /// The title of the link, usually shown as a tooltip.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub title: Option<String>,
 }
 
 impl Link {
@@ -32,22 +45,91 @@ impl Link {
             text: text.into(),
             footer,
             inlined,
+            label: None,
+            title: None,
         }
     }
+
+    /// Returns the label used to reference the footer.
+    pub fn footer_label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.text)
+    }
+}
+
+/// Renders the destination of a link or image, followed by its title.
+///
+/// Destinations containing whitespace, parentheses, angle brackets or
+/// backslashes are wrapped in `<>`, line breaks are percent-encoded as they
+/// cannot be escaped. Backslashes are bracketed as they would otherwise escape
+/// the character following them, e.g. the closing `)` of `[x](a\)`.
+pub(crate) fn destination(url: &str, title: Option<&str>) -> String {
+    let needs_brackets = url.is_empty()
+        || url
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || "()<>\\".contains(c));
+
+    let mut output = if needs_brackets {
+        let mut escaped = String::from("<");
+        for c in url.chars() {
+            match c {
+                '\n' => escaped.push_str("%0A"),
+                '\r' => escaped.push_str("%0D"),
+                '<' | '>' | '\\' => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                c => escaped.push(c),
+            }
+        }
+        escaped.push('>');
+        escaped
+    } else {
+        url.to_string()
+    };
+
+    if let Some(title) = title {
+        output.push_str(&format!(
+            " \"{}\"",
+            title.replace('\\', "\\\\").replace('"', "\\\"")
+        ));
+    }
+
+    output
+}
+
+/// Escapes text, e.g. a URL shown as the text of a link, so characters such as
+/// `_`, `*` or `]` are not read as markdown.
+pub(crate) fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\`*_[]<>~&$#".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
 }
 
 impl AsFooter for Link {
     fn as_footer(&self) -> Box<dyn MarkdownElement> {
-        Box::new(format!("[{}]: {}", self.text, self.url))
+        Box::new(format!(
+            "[{}]: {}",
+            self.footer_label(),
+            destination(&self.url, self.title.as_deref())
+        ))
     }
 }
 
 impl fmt::Display for Link {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let text = if self.footer {
-            format!("[{}][{}]", self.text, self.text)
+            format!("[{}][{}]", self.text, self.footer_label())
         } else {
-            format!("[{}]({})", self.text, self.url)
+            format!(
+                "[{}]({})",
+                self.text,
+                destination(&self.url, self.title.as_deref())
+            )
         };
 
         if self.inlined {
@@ -67,8 +149,8 @@ mod tests {
         let link = Link::new();
         assert_eq!(link.url, "");
         assert_eq!(link.text, "");
-        assert_eq!(link.footer, false);
-        assert_eq!(link.inlined, false);
+        assert!(!link.footer);
+        assert!(!link.inlined);
     }
 
     #[test]
@@ -76,8 +158,8 @@ mod tests {
         let link = Link::from("https://example.com", "example.com", false, true);
         assert_eq!(link.url, "https://example.com");
         assert_eq!(link.text, "example.com");
-        assert_eq!(link.footer, false);
-        assert_eq!(link.inlined, true);
+        assert!(!link.footer);
+        assert!(link.inlined);
     }
 
     #[test]
@@ -96,6 +178,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_link_footer_label() {
+        let mut link = Link::from("https://example.com", "example.com", true, true);
+        link.label = Some("example-2".into());
+
+        assert_eq!(link.render(), "[example.com][example-2]");
+        assert_eq!(
+            link.as_footer().render(),
+            "[example-2]: https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_link_title() {
+        let mut link = Link::from("https://example.com", "example", false, true);
+        link.title = Some("Say \"hi\"".into());
+        assert_eq!(
+            link.render(),
+            "[example](https://example.com \"Say \\\"hi\\\"\")"
+        );
+
+        link.footer = true;
+        assert_eq!(
+            link.as_footer().render(),
+            "[example]: https://example.com \"Say \\\"hi\\\"\""
+        );
+    }
+
+    #[test]
+    fn test_link_destination_escaping() {
+        let link = |url: &str| Link::from(url, "file", false, true).render();
+
+        assert_eq!(link("docs/My File.md"), "[file](<docs/My File.md>)");
+        assert_eq!(link("a_(b).md"), "[file](<a_(b).md>)");
+        assert_eq!(link("a<b>\nc"), "[file](<a\\<b\\>%0Ac>)");
+        assert_eq!(link(""), "[file](<>)");
+        assert_eq!(link("a\\"), "[file](<a\\\\>)");
+        assert_eq!(link("a\\*b"), "[file](<a\\\\*b>)");
+    }
+
     #[test]
     fn test_link_url_text_footer() {
         let link = Link::from("https://example.com", "example.com", true, false);
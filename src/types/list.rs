@@ -1,32 +1,161 @@
-use crate::MarkdownElement;
+use crate::{
+    flavor::Flavor,
+    types::{
+        node::{Node, Nodes},
+        paragraph::escape_dollars,
+    },
+};
 use std::fmt;
 
 /// The type of list.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ListVariant {
     /// An ordered list prefixes all its items using incrementing numbers.
     Ordered,
     /// An unordered list prefixes all its items using a dash.
+    #[default]
     Unordered,
 }
 
-impl Default for ListVariant {
-    fn default() -> Self {
-        Self::Unordered
+/// The character following the number of an ordered list item.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ListDelimiter {
+    /// A period, e.g. `1.`.
+    #[default]
+    Period,
+    /// A closing parenthesis, e.g. `1)`.
+    Parenthesis,
+}
+
+impl ListDelimiter {
+    fn char(self) -> char {
+        match self {
+            Self::Period => '.',
+            Self::Parenthesis => ')',
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The number of checked and unchecked checkboxes of a list.
+///
+/// Created by [List::progress](struct.List.html#method.progress).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Progress {
+    /// The number of checked checkboxes.
+    pub checked: usize,
+    /// The number of unchecked checkboxes.
+    pub unchecked: usize,
+}
+
+impl Progress {
+    /// Returns the total number of checkboxes.
+    pub fn total(&self) -> usize {
+        self.checked + self.unchecked
+    }
+
+    /// Returns whether every checkbox is checked.
+    ///
+    /// A list without checkboxes is complete.
+    pub fn is_complete(&self) -> bool {
+        self.unchecked == 0
+    }
+
+    /// Returns the share of checked checkboxes, from `0.0` to `1.0`.
+    ///
+    /// A list without checkboxes has a ratio of `1.0`.
+    pub fn ratio(&self) -> f64 {
+        match self.total() {
+            0 => 1.0,
+            total => self.checked as f64 / total as f64,
+        }
+    }
+
+    /// Renders a progress bar of the given width, e.g. `███░░░░`.
+    pub fn bar(&self, width: usize) -> String {
+        let filled = (self.ratio() * width as f64).round() as usize;
+        format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+    }
+}
+
+impl fmt::Display for Progress {
+    /// Formats the progress as `3/7 done`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} done", self.checked, self.total())
     }
 }
 
 /// An item inside a markdown list.
-pub type ListItem = Box<dyn MarkdownElement>;
+pub type ListItem = Node;
 
 /// A markdown list.
 ///
 /// Please use the [builders](module.builder.html) to safely create Markdown
 /// compliant documents!
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct List {
     pub items: Vec<ListItem>,
     pub variant: ListVariant,
+    /// The number of the first item of an ordered list, e.g. to continue a
+    /// list after an interrupting code block.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "default_start", skip_serializing_if = "is_default_start")
+    )]
+    pub start: usize,
+    /// The character following the numbers of an ordered list.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "ListDelimiter::is_default")
+    )]
+    pub delimiter: ListDelimiter,
+    /// Whether every item of an ordered list repeats the start number, which
+    /// keeps diffs small when items are inserted.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub lazy: bool,
+    /// Whether the items are separated by blank lines.
+    ///
+    /// Note that CommonMark renders a list as loose anyway once an item holds
+    /// several blocks separated by a blank line.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub loose: bool,
+}
+
+fn default_start() -> usize {
+    1
+}
+
+#[cfg(feature = "serde")]
+fn is_default_start(start: &usize) -> bool {
+    *start == default_start()
+}
+
+impl Default for List {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            variant: ListVariant::default(),
+            start: default_start(),
+            delimiter: ListDelimiter::default(),
+            lazy: false,
+            loose: false,
+        }
+    }
 }
 
 impl List {
@@ -51,40 +180,141 @@ impl List {
     }
 
     /// Creates a new ordered `List` with the given items.
-    pub fn ordered_with(items: Vec<ListItem>) -> Self {
+    pub fn ordered_with(items: Vec<impl Into<ListItem>>) -> Self {
         Self {
-            items,
+            items: items.into_iter().map(Into::into).collect(),
             variant: ListVariant::Ordered,
+            ..Default::default()
         }
     }
 
     /// Creates a new unordered `List` with the given items.
-    pub fn unordered_with(items: Vec<ListItem>) -> Self {
+    pub fn unordered_with(items: Vec<impl Into<ListItem>>) -> Self {
         Self {
-            items,
+            items: items.into_iter().map(Into::into).collect(),
             variant: ListVariant::Unordered,
+            ..Default::default()
         }
     }
-}
 
-impl fmt::Display for List {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (idx, item) in self.items.iter().enumerate() {
-            let marker = match self.variant {
-                ListVariant::Ordered => format!("{}.", idx + 1),
-                ListVariant::Unordered => "-".into(),
+    /// Sets the number of the first item of an ordered list.
+    pub fn set_start(&mut self, start: usize) -> &mut Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets the character following the numbers of an ordered list.
+    pub fn set_delimiter(&mut self, delimiter: ListDelimiter) -> &mut Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether every item of an ordered list repeats the start number.
+    pub fn set_lazy(&mut self, lazy: bool) -> &mut Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Sets whether the items are separated by blank lines.
+    pub fn set_loose(&mut self, loose: bool) -> &mut Self {
+        self.loose = loose;
+        self
+    }
+
+    /// Returns the marker of every item, e.g. `1. ` or `- `.
+    ///
+    /// The numbers of an ordered list are padded to the widest marker, so the
+    /// content of all items starts at the same column.
+    pub(crate) fn markers(&self) -> Vec<String> {
+        if self.variant == ListVariant::Unordered {
+            return vec!["- ".to_string(); self.items.len()];
+        }
+
+        let numbers: Vec<String> = (0..self.items.len())
+            .map(|idx| {
+                let number = if self.lazy {
+                    self.start
+                } else {
+                    self.start + idx
+                };
+                format!("{}{}", number, self.delimiter.char())
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or(0);
+
+        numbers
+            .into_iter()
+            .map(|number| format!("{:<width$}", number, width = width + 1))
+            .collect()
+    }
+
+    /// Counts the checked and unchecked checkboxes, including the ones of
+    /// tasks and nested lists.
+    pub fn progress(&self) -> Progress {
+        let mut progress = Progress::default();
+
+        for node in Nodes::new(&self.items) {
+            let checkbox = match node {
+                Node::Checkbox(checkbox) => checkbox,
+                Node::Task(task) => &task.checkbox,
+                _ => continue,
+            };
+
+            if checkbox.is_checked() {
+                progress.checked += 1;
+            } else {
+                progress.unchecked += 1;
+            }
+        }
+
+        progress
+    }
+
+    /// Renders the list for the given flavor.
+    ///
+    /// Items spanning multiple lines, e.g. wrapped paragraphs, nested lists or
+    /// items made of several blocks, have their following lines indented to
+    /// the item's content.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        let mut output = String::new();
+
+        for (idx, (item, marker)) in self.items.iter().zip(self.markers()).enumerate() {
+            if self.loose && idx != 0 {
+                output.push('\n');
+            }
+
+            let indent = " ".repeat(marker.len());
+
+            let rendered = match item {
+                Node::Text(text) if flavor.supports_math() => escape_dollars(text),
+                item => item.render_with(flavor),
             };
-            writeln!(f, "{} {}", marker, item.render().trim_end_matches("\n"))?;
+
+            for (line_idx, line) in rendered.trim_end_matches('\n').split('\n').enumerate() {
+                if line_idx == 0 {
+                    output.push_str(&marker);
+                } else if !line.is_empty() {
+                    output.push_str(&indent);
+                }
+                output.push_str(line);
+                output.push('\n');
+            }
         }
 
-        Ok(())
+        output
+    }
+}
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Paragraph;
+    use crate::{MarkdownElement, Paragraph};
 
     #[test]
     fn test_ordered_list_one_entry() {
@@ -128,6 +358,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multi_line_items_are_indented() {
+        let nested = List::unordered_with(vec![Paragraph::from("a"), Paragraph::from("b")]);
+        let mut items: Vec<Node> = (1..10).map(|n| Node::Text(n.to_string())).collect();
+        items.push(Node::from(nested));
+
+        assert_eq!(
+            List::ordered_with(items)
+                .render()
+                .lines()
+                .skip(9)
+                .collect::<Vec<_>>(),
+            vec!["10. - a", "    - b"]
+        );
+    }
+
+    #[test]
+    fn test_padded_markers() {
+        let items: Vec<Node> = (1..11).map(|n| Node::Text(n.to_string())).collect();
+        let lines: Vec<String> = List::ordered_with(items)
+            .render()
+            .lines()
+            .map(String::from)
+            .collect();
+
+        assert_eq!(lines[0], "1.  1");
+        assert_eq!(lines[9], "10. 10");
+    }
+
+    #[test]
+    fn test_start_and_delimiter() {
+        let mut list = List::ordered_with(vec!["a", "b"]);
+        list.set_start(9).set_delimiter(ListDelimiter::Parenthesis);
+
+        assert_eq!(list.render(), "9)  a\n10) b\n");
+    }
+
+    #[test]
+    fn test_lazy_numbering() {
+        let mut list = List::ordered_with(vec!["a", "b", "c"]);
+        list.set_lazy(true);
+        assert_eq!(list.render(), "1. a\n1. b\n1. c\n");
+
+        list.set_start(4);
+        assert_eq!(list.render(), "4. a\n4. b\n4. c\n");
+    }
+
+    #[test]
+    fn test_progress() {
+        let list = List::builder()
+            .checkbox("Plan", true)
+            .task(
+                "Deploy",
+                false,
+                List::builder()
+                    .checkbox("Build", true)
+                    .checkbox("Upload", false)
+                    .unordered(),
+            )
+            .append(List::builder().checkbox("Announce", false).unordered())
+            .ordered();
+
+        let progress = list.progress();
+        assert_eq!(
+            progress,
+            Progress {
+                checked: 2,
+                unchecked: 3
+            }
+        );
+        assert_eq!(progress.to_string(), "2/5 done");
+        assert_eq!(progress.bar(10), "████░░░░░░");
+        assert!(!progress.is_complete());
+        assert!(List::new().progress().is_complete());
+    }
+
+    #[test]
+    fn test_loose_list() {
+        let mut list = List::unordered_with(vec!["a", "b"]);
+        list.set_loose(true);
+
+        assert_eq!(list.render(), "- a\n\n- b\n");
+    }
+
+    #[test]
+    fn test_wrapped_paragraph_is_indented() {
+        let text = "word ".repeat(40);
+        let list = List::ordered_with(vec![Paragraph::from(text.trim_end())]);
+        let rendered = list.render();
+
+        assert!(rendered.lines().count() > 1);
+        for line in rendered.lines().skip(1) {
+            assert!(line.starts_with("   word"));
+        }
+    }
+
     #[test]
     fn test_default_list() {
         let list = List::new();
@@ -148,4 +474,12 @@ mod tests {
         assert_eq!(list.variant, ListVariant::Ordered);
         assert_eq!(list.items.len(), 0);
     }
+
+    #[test]
+    fn test_dollars_escaped_for_math_flavors() {
+        let list = List::unordered_with(vec!["$5", "`$HOME`"]);
+
+        assert_eq!(list.render_with(Flavor::Pandoc), "- \\$5\n- `$HOME`\n");
+        assert_eq!(list.render_with(Flavor::CommonMark), "- $5\n- `$HOME`\n");
+    }
 }
@@ -1,18 +1,54 @@
 use crate::{
+    flavor::Flavor,
     traits::{AsFooter, MarkdownElement},
-    types::{header::Header, link::Link, list::List, paragraph::Paragraph},
+    transforms::Bold,
+    types::{
+        badge::{Badge, BadgeRow},
+        definition_list::DefinitionList,
+        details::Details,
+        diagram::Diagram,
+        header::{Header, HeaderLevel, HeaderLevelError, HeaderOverflow},
+        include::Include,
+        link::{destination, Link},
+        list::List,
+        math::MathBlock,
+        node::{Node, Nodes},
+        paragraph::Paragraph,
+        quote::Quote,
+        section::Section,
+    },
+    visit::{walk_node_mut, Visitor, VisitorMut},
     Image,
 };
 use std::fmt;
 use tousize::ToUsize;
 
 /// A markdown document.
-#[derive(Default)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Markdown {
     /// The markdown elements.
-    pub elements: Vec<Box<dyn MarkdownElement>>,
-    /// The markdown footer elements.
-    pub footers: Vec<Box<dyn MarkdownElement>>,
+    pub elements: Vec<Node>,
+    /// Additional markdown footer elements.
+    ///
+    /// The footers of links and images are not stored here, they are created
+    /// from the elements when rendering.
+    pub footers: Vec<Node>,
+    /// The flavor the document is rendered for.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Flavor::is_default")
+    )]
+    pub flavor: Flavor,
+    /// Whether blocks are separated by a single line break instead of a blank
+    /// line where this does not change how they are parsed, e.g. after
+    /// headers. Also applies to the blocks inside of sections, quotes, details
+    /// and included documents, overriding their own setting.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub compact: bool,
 }
 
 impl Markdown {
@@ -26,12 +62,20 @@ impl Markdown {
         elements: Vec<Box<dyn MarkdownElement>>,
         footers: Vec<Box<dyn MarkdownElement>>,
     ) -> Self {
-        Self { elements, footers }
+        Self {
+            elements: elements.into_iter().map(Node::from).collect(),
+            footers: footers.into_iter().map(Node::from).collect(),
+            flavor: Flavor::default(),
+            compact: false,
+        }
     }
 
     /// Adds any generic markdown element.
+    ///
+    /// Built-in elements are stored as their [Node](../node/enum.Node.html)
+    /// variant, allowing them to be inspected later on.
     pub fn add(&mut self, element: impl MarkdownElement + 'static) -> &mut Self {
-        self.elements.push(Box::new(element));
+        self.elements.push(Node::from_element(element));
         self
     }
 
@@ -47,7 +91,7 @@ impl Markdown {
     /// Panics if the header level is not valid (one to six inclusive).
     pub fn header(&mut self, text: impl Into<String>, level: impl ToUsize) -> &mut Self {
         let header = Header::from(text, level);
-        self.elements.push(Box::new(header));
+        self.elements.push(Node::Header(header));
         self
     }
 
@@ -117,7 +161,7 @@ impl Markdown {
     ///
     /// - `list`: The list instance to add.
     pub fn list(&mut self, list: List) -> &mut Self {
-        self.elements.push(Box::new(list));
+        self.elements.push(Node::List(list));
         self
     }
 
@@ -129,13 +173,10 @@ impl Markdown {
     ///
     /// # Note
     ///
-    /// The associated footer element is added as well if the passed link is
+    /// The associated footer element is rendered as well if the passed link is
     /// marked as footer.
     pub fn link(&mut self, link: Link) -> &mut Self {
-        if link.footer {
-            self.footers.push(link.as_footer());
-        }
-        self.elements.push(Box::new(link));
+        self.elements.push(Node::Link(link));
         self
     }
 
@@ -147,13 +188,10 @@ impl Markdown {
     ///
     /// # Note
     ///
-    /// The associated footer element is added as well if the passed link is
-    /// marked as footer.
+    /// The associated footer element is rendered as well if the passed image
+    /// is marked as footer.
     pub fn image(&mut self, image: Image) -> &mut Self {
-        if image.footer {
-            self.footers.push(image.as_footer());
-        }
-        self.elements.push(Box::new(image));
+        self.elements.push(Node::Image(image));
         self
     }
 
@@ -163,36 +201,764 @@ impl Markdown {
     ///
     /// - `text`: The paragraph's text.
     pub fn paragraph(&mut self, text: impl Into<String>) -> &mut Self {
-        self.elements.push(Box::new(Paragraph::from(text)));
+        self.elements.push(Node::Paragraph(Paragraph::from(text)));
+        self
+    }
+
+    /// Sets the flavor the document is rendered for.
+    ///
+    /// Elements using syntax that is not supported by the flavor fall back to
+    /// an equivalent, see [Flavor](../../flavor/enum.Flavor.html).
+    pub fn set_flavor(&mut self, flavor: Flavor) -> &mut Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Sets whether blocks are separated by a single line break instead of a
+    /// blank line, see
+    /// [compact](struct.Markdown.html#structfield.compact).
+    pub fn set_compact(&mut self, compact: bool) -> &mut Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Adds a definition list to the document.
+    ///
+    /// # Arguments
+    ///
+    /// - `list`: The definition list instance to add.
+    pub fn definition_list(&mut self, list: DefinitionList) -> &mut Self {
+        self.elements.push(Node::DefinitionList(list));
+        self
+    }
+
+    /// Adds a block quote to the document.
+    ///
+    /// # Arguments
+    ///
+    /// - `quote`: The quote instance to add.
+    pub fn quote(&mut self, quote: Quote) -> &mut Self {
+        self.elements.push(Node::Quote(quote));
+        self
+    }
+
+    /// Adds a section to the document.
+    ///
+    /// Sections added to the document use level 1 headers, nested sections
+    /// are one level below their parent.
+    ///
+    /// # Arguments
+    ///
+    /// - `title`: The section's title.
+    /// - `build`: Called with the new section to add its content.
+    pub fn section(
+        &mut self,
+        title: impl Into<String>,
+        build: impl FnOnce(&mut Section),
+    ) -> &mut Self {
+        let mut section = Section::new(title);
+        build(&mut section);
+        self.elements.push(Node::Section(section));
+        self
+    }
+
+    /// Adds a math block to the document.
+    ///
+    /// # Arguments
+    ///
+    /// - `tex`: The TeX source of the formula.
+    pub fn math(&mut self, tex: impl Into<String>) -> &mut Self {
+        self.elements.push(Node::MathBlock(MathBlock::from(tex)));
+        self
+    }
+
+    /// Adds a row of badges to the document.
+    ///
+    /// The row is added as a paragraph of images, so the badges are found by
+    /// e.g. [images](struct.Markdown.html#method.images) and visitors.
+    ///
+    /// # Arguments
+    ///
+    /// - `badges`: The badges, from left to right.
+    pub fn badges(&mut self, badges: Vec<Badge>) -> &mut Self {
+        if !badges.is_empty() {
+            self.elements.push(BadgeRow::with(badges).into());
+        }
+        self
+    }
+
+    /// Adds a diagram to the document.
+    ///
+    /// # Arguments
+    ///
+    /// - `diagram`: The diagram instance to add.
+    pub fn diagram(&mut self, diagram: Diagram) -> &mut Self {
+        self.elements.push(Node::Diagram(diagram));
+        self
+    }
+
+    /// Adds collapsible details to the document.
+    ///
+    /// # Arguments
+    ///
+    /// - `summary`: The always visible summary.
+    /// - `build`: Called with the new details to add their content.
+    pub fn details(
+        &mut self,
+        summary: impl Into<String>,
+        build: impl FnOnce(&mut Details),
+    ) -> &mut Self {
+        let mut details = Details::new(summary);
+        build(&mut details);
+        self.elements.push(Node::Details(details));
+        self
+    }
+
+    /// Returns the section found by following the titles of `path`, starting
+    /// at the top-level sections.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The titles of the section and its parents, e.g.
+    ///   `["Install", "Linux"]`.
+    pub fn find_section<I, S>(&self, path: I) -> Option<&Section>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut path = path.into_iter();
+        let first = path.next()?;
+
+        self.elements
+            .iter()
+            .find_map(|node| match node {
+                Node::Section(section) if section.title() == first.as_ref() => Some(section),
+                _ => None,
+            })?
+            .find_section(path)
+    }
+
+    /// Returns the section found by following the titles of `path` mutably,
+    /// allowing to edit it.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The titles of the section and its parents, e.g.
+    ///   `["Install", "Linux"]`.
+    pub fn section_mut<I, S>(&mut self, path: I) -> Option<&mut Section>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut path = path.into_iter();
+        let first = path.next()?;
+
+        self.elements
+            .iter_mut()
+            .find_map(|node| match node {
+                Node::Section(section) if section.title() == first.as_ref() => Some(section),
+                _ => None,
+            })?
+            .section_mut(path)
+    }
+
+    /// Returns an iterator over the top-level elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, Node> {
+        self.elements.iter()
+    }
+
+    /// Returns an iterator that allows modifying the top-level elements.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Node> {
+        self.elements.iter_mut()
+    }
+
+    /// Returns an iterator over all elements, including nested ones such as
+    /// list items, in document order.
+    pub fn nodes(&self) -> Nodes<'_> {
+        Nodes::new(&self.elements)
+    }
+
+    /// Returns an iterator over all headers of the document, including the
+    /// headers of sections.
+    pub fn headers(&self) -> impl Iterator<Item = &Header> {
+        self.nodes().filter_map(|node| match node {
+            Node::Header(header) => Some(header),
+            Node::Section(section) => Some(&section.header),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over all links of the document, including nested
+    /// ones.
+    pub fn links(&self) -> impl Iterator<Item = &Link> {
+        self.nodes().filter_map(|node| match node {
+            Node::Link(link) => Some(link),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over all images of the document, including nested
+    /// ones.
+    pub fn images(&self) -> impl Iterator<Item = &Image> {
+        self.nodes().filter_map(|node| match node {
+            Node::Image(image) => Some(image),
+            _ => None,
+        })
+    }
+
+    /// Walks the document using the given visitor.
+    ///
+    /// Each top-level element is passed to
+    /// [visit_node](../../visit/trait.Visitor.html#method.visit_node), which
+    /// recurses into nested elements by default.
+    pub fn walk(&self, visitor: &mut impl Visitor) {
+        for node in &self.elements {
+            visitor.visit_node(node);
+        }
+    }
+
+    /// Walks the document using the given visitor, allowing it to modify
+    /// every element.
+    pub fn walk_mut(&mut self, visitor: &mut impl VisitorMut) -> &mut Self {
+        for node in &mut self.elements {
+            visitor.visit_node_mut(node);
+        }
+        self
+    }
+
+    /// Shifts the level of every header by `delta`, clamping levels that would
+    /// leave the valid range.
+    ///
+    /// A positive `delta` makes the headers less important, e.g. a shift by one
+    /// turns every `h1` into an `h2`.
+    pub fn shift_headers(&mut self, delta: isize) -> &mut Self {
+        self.walk_mut(&mut ShiftHeaders {
+            delta,
+            overflow: HeaderOverflow::Clamp,
+        })
+    }
+
+    /// Shifts the level of every header by `delta`, handling levels that would
+    /// leave the valid range as specified by `overflow`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `overflow` is [HeaderOverflow::Error] and any header would be
+    /// shifted to an invalid level. The document is left untouched in that
+    /// case.
+    pub fn shift_headers_with(
+        &mut self,
+        delta: isize,
+        overflow: HeaderOverflow,
+    ) -> Result<&mut Self, HeaderLevelError> {
+        if overflow == HeaderOverflow::Error {
+            if let Some(header) = self
+                .headers()
+                .find(|header| header.level.shifted(delta).is_none())
+            {
+                return Err(HeaderLevelError {
+                    text: header.text.clone(),
+                    level: header.level.value() as isize + delta,
+                });
+            }
+        }
+
+        Ok(self.walk_mut(&mut ShiftHeaders { delta, overflow }))
+    }
+
+    /// Embeds another document, re-leveling its headers so they nest under the
+    /// current section.
+    ///
+    /// The most important header of `other` is moved to `base_level`, all
+    /// other headers keep their distance to it. Levels that would go past six
+    /// are clamped. The elements and footers of `other` are appended.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The document to embed.
+    /// - `base_level`: The level of the top-most header of `other` once
+    ///   embedded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_level` is not valid (one to six inclusive).
+    pub fn embed(&mut self, other: Markdown, base_level: impl ToUsize) -> &mut Self {
+        self.embed_with(other, base_level, HeaderOverflow::Clamp)
+            .expect("clamping never fails")
+    }
+
+    /// Embeds another document like [embed](struct.Markdown.html#method.embed),
+    /// handling header levels that would go past six as specified by
+    /// `overflow`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `overflow` is [HeaderOverflow::Error] and any header of `other`
+    /// would be shifted to an invalid level. Nothing is embedded in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_level` is not valid (one to six inclusive).
+    pub fn embed_with(
+        &mut self,
+        mut other: Markdown,
+        base_level: impl ToUsize,
+        overflow: HeaderOverflow,
+    ) -> Result<&mut Self, HeaderLevelError> {
+        let base_level = HeaderLevel::from(base_level);
+
+        if let Some(top) = other.headers().map(|header| header.level.value()).min() {
+            let delta = base_level.value() as isize - top as isize;
+            other.shift_headers_with(delta, overflow)?;
+        }
+
+        Ok(self.append(other))
+    }
+
+    /// Appends the elements and footers of another document.
+    ///
+    /// Footers present in both documents are only rendered once. Footer labels
+    /// of `other` that are already used for a different url are renamed by
+    /// appending a number, e.g. `[docs]` becomes `[docs-2]`. Links and images
+    /// of `other` are updated to use the new label.
+    pub fn append(&mut self, mut other: Markdown) -> &mut Self {
+        self.reconcile_footers(&mut other);
+        self.elements.append(&mut other.elements);
+        self.footers.append(&mut other.footers);
+        self
+    }
+
+    /// Embeds another document in place using an
+    /// [Include](../include/struct.Include.html).
+    ///
+    /// The footers of `other` are merged like in
+    /// [append](struct.Markdown.html#method.append).
+    pub fn include(&mut self, mut other: Markdown) -> &mut Self {
+        self.reconcile_footers(&mut other);
+        self.elements.push(Node::Include(Include::from(other)));
         self
     }
 
+    /// Renames the footer labels of `other` that clash with footers of this
+    /// document, and drops the additional footers already present.
+    fn reconcile_footers(&self, other: &mut Markdown) {
+        let mut renamer = RenameFooters {
+            taken: self
+                .collect_footers()
+                .iter()
+                .filter_map(|footer| parse_footer(footer))
+                .map(|(label, url)| (normalize_label(&label), url))
+                .collect(),
+            renamed: Vec::new(),
+        };
+
+        for footer in &mut other.footers {
+            let Some((label, url)) = parse_footer(&footer.render()) else {
+                continue;
+            };
+            if let Some(renamed) = renamer.resolve(&label, &url) {
+                *footer = Node::Text(format!("[{}]: {}", renamed, url));
+            }
+        }
+        other.walk_mut(&mut renamer);
+
+        let existing: Vec<String> = self.footers.iter().map(|footer| footer.render()).collect();
+        other
+            .footers
+            .retain(|footer| !existing.contains(&footer.render()));
+    }
+
+    /// Returns the footers of the document.
+    ///
+    /// These are the footers of all links and images marked as footer and the
+    /// footers of included documents, including nested ones, followed by the
+    /// additional [footers](struct.Markdown.structfield.footers). Duplicates
+    /// are removed.
+    pub fn collect_footers(&self) -> Vec<String> {
+        let mut candidates = Vec::new();
+        collect_footer_candidates(&self.elements, self.flavor, &mut candidates);
+        candidates.extend(self.footers.iter().map(|footer| footer.render()));
+
+        let mut footers: Vec<String> = Vec::new();
+        for footer in candidates {
+            if !footers.contains(&footer) {
+                footers.push(footer);
+            }
+        }
+
+        footers
+    }
+
     /// Renders the markdown document to a `String`.
     ///
     /// The method does render each
-    /// [element](struct.Markdown.structfield.elements) in order, followed by
-    /// each [footer](struct.Markdown.structfield.footers).
+    /// [element](struct.Markdown.structfield.elements) in order for the
+    /// document's [flavor](struct.Markdown.html#structfield.flavor), followed
+    /// by the [footers](struct.Markdown.html#method.collect_footers).
     pub fn render(&self) -> String {
         self.to_string()
     }
+
+    /// Renders the elements for the given flavor, without the footers.
+    ///
+    /// Every element is a block of its own: blocks are separated by exactly one
+    /// blank line, or a single line break if `compact` is set, regardless of
+    /// the line breaks the elements render themselves.
+    pub(crate) fn render_elements(&self, flavor: Flavor, compact: bool) -> String {
+        join_blocks(
+            self.elements
+                .iter()
+                .map(|element| element.render_block(flavor, compact)),
+            compact,
+        )
+    }
 }
 
-impl fmt::Display for Markdown {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (index, element) in self.elements.iter().enumerate() {
-            if index == self.elements.len() - 1 {
-                write!(f, "{}", element.render())?;
+/// Joins rendered blocks, ending with a single line break.
+///
+/// Blank lines around the blocks are removed and empty blocks are skipped, so
+/// elements do not need to know how they are separated. Blocks are separated
+/// by a blank line, or by a single line break if `compact` is set and the
+/// blocks cannot merge, e.g. text following a paragraph would continue it and
+/// a `---` following it would turn it into a header.
+pub(crate) fn join_blocks(blocks: impl IntoIterator<Item = String>, compact: bool) -> String {
+    let mut output = String::new();
+    let mut previous = 0;
+
+    for block in blocks {
+        let block = trim_blank_lines(&block);
+        if block.is_empty() {
+            continue;
+        }
+
+        if !output.is_empty() {
+            if compact && can_follow_directly(&output[previous..], block) {
+                output.push('\n');
             } else {
-                writeln!(f, "{}", element.render())?;
+                output.push_str("\n\n");
+            }
+        }
+        previous = output.len();
+        output.push_str(block);
+    }
+
+    if !output.is_empty() {
+        output.push('\n');
+    }
+    output
+}
+
+/// Returns whether the block can follow the previous block on the next line
+/// without a blank line in between.
+///
+/// This is the case after a block that cannot be continued, i.e. a header, a
+/// thematic break or a code block, and before a header or code block, which
+/// interrupt anything but HTML. Lists interrupt paragraphs too, but would
+/// merge with a previous list.
+fn can_follow_directly(previous: &str, block: &str) -> bool {
+    let last = previous.lines().last().unwrap_or_default();
+    let first = block.lines().next().unwrap_or_default();
+    let previous_first = previous.lines().next().unwrap_or_default();
+
+    let is_closed = is_atx_header(last) || is_thematic_break(last) || closes_fence(previous);
+    let interrupts = !last.starts_with('<')
+        && (is_atx_header(first)
+            || fence_of(first).is_some()
+            || (interrupts_paragraph_as_list(first) && list_marker(previous_first).is_none()));
+
+    is_closed || interrupts
+}
+
+/// Returns the text following the list marker the line starts with, e.g.
+/// `item` for `- item` or `3) item`.
+fn list_marker(line: &str) -> Option<&str> {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let rest = if digits == 0 {
+        line.strip_prefix(['-', '*', '+'])?
+    } else {
+        line[digits..].strip_prefix(['.', ')'])?
+    };
+
+    (rest.is_empty() || rest.starts_with(' ')).then(|| rest.trim_start())
+}
+
+/// Returns whether the line starts a list that can interrupt a paragraph: a
+/// non-empty item that is either a bullet or numbered one.
+fn interrupts_paragraph_as_list(line: &str) -> bool {
+    let bullet_or_one =
+        line.starts_with(['-', '*', '+']) || line.starts_with("1.") || line.starts_with("1)");
+    bullet_or_one
+        && !is_thematic_break(line)
+        && list_marker(line).is_some_and(|rest| !rest.is_empty())
+}
+
+/// Returns whether the line is an ATX header, e.g. `## Usage`.
+fn is_atx_header(line: &str) -> bool {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    (1..=6).contains(&level) && (line[level..].is_empty() || line[level..].starts_with(' '))
+}
+
+/// Returns whether the line is a thematic break, e.g. `---` or `* * *`.
+fn is_thematic_break(line: &str) -> bool {
+    let mut chars = line.chars().filter(|c| *c != ' ');
+    let Some(first) = chars.next().filter(|c| "-*_".contains(*c)) else {
+        return false;
+    };
+
+    !line.starts_with(' ') && chars.clone().count() >= 2 && chars.all(|c| c == first)
+}
+
+/// Returns the character and length of the code fence the line opens.
+fn fence_of(line: &str) -> Option<(char, usize)> {
+    ['`', '~'].into_iter().find_map(|c| {
+        let len = line.chars().take_while(|t| *t == c).count();
+        (len >= 3).then_some((c, len))
+    })
+}
+
+/// Returns whether the last line of the text closes the code block started
+/// by the last opening fence.
+fn closes_fence(text: &str) -> bool {
+    let mut fence: Option<(char, usize)> = None;
+    let mut closed = false;
+
+    for line in text.lines() {
+        closed = false;
+        match (fence, fence_of(line)) {
+            (None, Some(opening)) => fence = Some(opening),
+            (Some((open, open_len)), Some((c, len)))
+                if open == c && len >= open_len && line[len..].trim().is_empty() =>
+            {
+                fence = None;
+                closed = true;
+            }
+            _ => {}
+        }
+    }
+
+    closed
+}
+
+/// Removes leading blank lines and trailing whitespace.
+fn trim_blank_lines(block: &str) -> &str {
+    let block = block.trim_end();
+    let mut start = 0;
+    for line in block.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            break;
+        }
+        start += line.len();
+    }
+
+    &block[start..]
+}
+
+/// Collects the rendered footers of the nodes and their descendants, in
+/// document order.
+fn collect_footer_candidates<'a>(
+    nodes: impl IntoIterator<Item = &'a Node>,
+    flavor: Flavor,
+    candidates: &mut Vec<String>,
+) {
+    for node in nodes {
+        match node {
+            Node::Link(link) if link.footer => candidates.push(link.as_footer().render()),
+            Node::Image(image) => candidates.extend(image.footers(flavor)),
+            Node::Include(include) => {
+                collect_footer_candidates(&include.document.elements, flavor, candidates);
+                candidates.extend(
+                    include
+                        .document
+                        .footers
+                        .iter()
+                        .map(|footer| footer.render()),
+                );
+            }
+            _ => collect_footer_candidates(node.children(), flavor, candidates),
+        }
+    }
+}
+
+/// Splits a footer of the form `[label]: url` into its label and url.
+fn parse_footer(footer: &str) -> Option<(String, String)> {
+    let (label, url) = footer.trim().strip_prefix('[')?.split_once("]:")?;
+    Some((label.to_string(), url.trim().to_string()))
+}
+
+/// Normalizes a footer label, as labels are matched case-insensitively with
+/// collapsed whitespace.
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Renames the footer labels of links and images that clash with already
+/// taken labels.
+struct RenameFooters {
+    /// The normalized labels in use, along with their url.
+    taken: Vec<(String, String)>,
+    /// The renamed labels as `(normalized label, url, new label)`.
+    renamed: Vec<(String, String, String)>,
+}
+
+impl RenameFooters {
+    /// Returns the new label if the footer has to be renamed.
+    fn resolve(&mut self, label: &str, url: &str) -> Option<String> {
+        let key = normalize_label(label);
+
+        if let Some((_, _, renamed)) = self
+            .renamed
+            .iter()
+            .find(|(old, old_url, _)| *old == key && old_url == url)
+        {
+            return Some(renamed.clone());
+        }
+
+        match self.taken.iter().find(|(taken, _)| *taken == key) {
+            None => {
+                self.taken.push((key, url.to_string()));
+                None
+            }
+            Some((_, taken_url)) if taken_url == url => None,
+            Some(_) => {
+                let renamed = (2..)
+                    .map(|n| format!("{}-{}", label, n))
+                    .find(|candidate| {
+                        let candidate = normalize_label(candidate);
+                        !self.taken.iter().any(|(taken, _)| *taken == candidate)
+                    })
+                    .expect("there is always an unused label");
+
+                self.taken
+                    .push((normalize_label(&renamed), url.to_string()));
+                self.renamed.push((key, url.to_string(), renamed.clone()));
+                Some(renamed)
+            }
+        }
+    }
+}
+
+impl VisitorMut for RenameFooters {
+    fn visit_link_mut(&mut self, link: &mut Link) {
+        if link.footer {
+            let url = destination(&link.url, link.title.as_deref());
+            if let Some(renamed) = self.resolve(link.footer_label(), &url) {
+                link.label = Some(renamed);
+            }
+        }
+    }
+
+    fn visit_image_mut(&mut self, image: &mut Image) {
+        if image.footer {
+            let url = destination(&image.url, image.title.as_deref());
+            if let Some(renamed) = self.resolve(image.footer_label(), &url) {
+                image.label = Some(renamed);
+            }
+            if let Some(link) = &image.link {
+                let label = image.link_footer_label();
+                if let Some(renamed) = self.resolve(&label, &destination(link, None)) {
+                    image.link_label = Some(renamed);
+                }
+            }
+        }
+    }
+}
+
+/// Shifts every header it visits.
+struct ShiftHeaders {
+    delta: isize,
+    overflow: HeaderOverflow,
+}
+
+impl VisitorMut for ShiftHeaders {
+    fn visit_node_mut(&mut self, node: &mut Node) {
+        if let Node::Section(section) = node {
+            if self.overflow == HeaderOverflow::Demote
+                && section.header.level.shifted(self.delta).is_none()
+            {
+                // A section cannot hold a paragraph as its header, so it is
+                // replaced by its bold title followed by its content.
+                let mut content = Markdown::new();
+                content.elements.push(Node::Paragraph(Paragraph::from(
+                    section.header.text.to_bold(),
+                )));
+                content.elements.append(&mut section.children);
+                for child in &mut content.elements[1..] {
+                    self.visit_node_mut(child);
+                }
+
+                *node = Node::Include(Include::from(content));
+                return;
+            }
+        }
+
+        let Node::Header(header) = node else {
+            return walk_node_mut(self, node);
+        };
+
+        match (header.level.shifted(self.delta), self.overflow) {
+            (Some(level), _) => header.level = level,
+            (None, HeaderOverflow::Demote) => {
+                *node = Node::Paragraph(Paragraph::from(header.text.to_bold()));
             }
+            (None, _) => header.level = header.level.shifted_clamped(self.delta),
+        }
+    }
+
+    fn visit_header_mut(&mut self, header: &mut Header) {
+        header.level = header.level.shifted_clamped(self.delta);
+    }
+}
+
+impl<'a> IntoIterator for &'a Markdown {
+    type Item = &'a Node;
+    type IntoIter = std::slice::Iter<'a, Node>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Markdown {
+    type Item = &'a mut Node;
+    type IntoIter = std::slice::IterMut<'a, Node>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl Extend<Node> for Markdown {
+    fn extend<T: IntoIterator<Item = Node>>(&mut self, iter: T) {
+        self.elements.extend(iter);
+    }
+}
+
+impl Extend<Markdown> for Markdown {
+    /// Appends every document like
+    /// [append](struct.Markdown.html#method.append).
+    fn extend<T: IntoIterator<Item = Markdown>>(&mut self, iter: T) {
+        for other in iter {
+            self.append(other);
         }
+    }
+}
+
+impl fmt::Display for Markdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements = self.render_elements(self.flavor, self.compact);
+        f.write_str(&elements)?;
 
-        if !self.footers.is_empty() {
+        let footers = self.collect_footers();
+        if !footers.is_empty() && !elements.is_empty() {
             writeln!(f)?;
         }
 
-        for footer in &self.footers {
-            writeln!(f, "{}", footer.render())?;
+        for footer in footers {
+            writeln!(f, "{}", footer)?;
         }
 
         Ok(())
@@ -397,15 +1163,357 @@ mod tests {
     }
 
     #[test]
-    fn test_document_with_list() {
+    fn test_document_footers_are_deduplicated() {
+        let link = LinkBuilder::new()
+            .url("https://example.com")
+            .text("Example")
+            .footer()
+            .inlined()
+            .build();
+
         let mut doc = Markdown::new();
+        doc.link(link.clone()).link(link);
 
-        doc.list(
-            ListBuilder::new()
-                .append("First do this")
-                .append("Then do this")
-                .ordered(),
-        );
+        assert_eq!(
+            doc.render(),
+            "[Example][Example]\n\n[Example][Example]\n\n[Example]: https://example.com\n"
+        );
+    }
+
+    #[test]
+    fn test_document_block_separation() {
+        #[derive(Clone, Debug)]
+        struct Rule;
+
+        impl fmt::Display for Rule {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "\n\n---\n\n\n")
+            }
+        }
+
+        let mut doc = Markdown::new();
+        doc.h1("Title").add(Rule).add("Text").paragraph("More text");
+        assert_eq!(doc.render(), "# Title\n\n---\n\nText\n\nMore text\n");
+
+        doc.set_compact(true);
+        assert_eq!(doc.render(), "# Title\n---\nText\n\nMore text\n");
+    }
+
+    #[test]
+    fn test_document_compact_lists() {
+        let mut doc = Markdown::new();
+        doc.set_compact(true)
+            .paragraph("Steps:")
+            .list(List::unordered_with(vec!["One"]))
+            .list(List::unordered_with(vec!["Two"]))
+            .paragraph("Later:")
+            .list(List::ordered_with(vec!["Three"]).set_start(10).clone());
+
+        assert_eq!(
+            doc.render(),
+            "Steps:\n- One\n\n- Two\n\nLater:\n\n10. Three\n"
+        );
+    }
+
+    #[test]
+    fn test_document_compact_include() {
+        let mut included = Markdown::new();
+        included.h2("Usage").paragraph("Run it.");
+
+        let mut doc = Markdown::new();
+        doc.set_compact(true).h1("Title").include(included.clone());
+        assert_eq!(doc.render(), "# Title\n## Usage\nRun it.\n");
+
+        included.set_compact(true);
+        let mut doc = Markdown::new();
+        doc.h1("Title").include(included);
+        assert_eq!(doc.render(), "# Title\n\n## Usage\n\nRun it.\n");
+    }
+
+    #[test]
+    fn test_document_compact_nested_blocks() {
+        let mut doc = Markdown::new();
+        doc.set_compact(true)
+            .section("Install", |s| {
+                s.paragraph("Run it.").section("Linux", |s| {
+                    s.paragraph("Use apt.").paragraph("Or snap.");
+                });
+            })
+            .quote(Quote::with(vec![
+                Paragraph::from("Quoted."),
+                Paragraph::from("Again."),
+            ]))
+            .details("Logs", |d| {
+                d.paragraph("First.").paragraph("Second.");
+            });
+
+        assert_eq!(
+            doc.render(),
+            "# Install\nRun it.\n## Linux\nUse apt.\n\nOr snap.\n\n\
+            > Quoted.\n>\n> Again.\n\n\
+            <details>\n<summary>Logs</summary>\n\nFirst.\n\nSecond.\n\n</details>\n"
+        );
+    }
+
+    #[test]
+    fn test_document_skips_empty_blocks() {
+        let mut doc = Markdown::new();
+        assert_eq!(doc.render(), "");
+
+        doc.add("").add("  \n").paragraph("Text").add("\n");
+        assert_eq!(doc.render(), "Text\n");
+    }
+
+    #[test]
+    fn test_document_nested_footers() {
+        let mut doc = Markdown::new();
+        doc.list(
+            ListBuilder::new()
+                .append(
+                    LinkBuilder::new()
+                        .url("https://example.com")
+                        .text("Example")
+                        .footer()
+                        .build(),
+                )
+                .unordered(),
+        );
+
+        assert_eq!(
+            doc.render(),
+            "- [Example][Example]\n\n[Example]: https://example.com\n"
+        );
+    }
+
+    #[test]
+    fn test_document_shift_headers() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").h2("Section").h6("Deep");
+
+        doc.shift_headers(1);
+        assert_eq!(doc.render(), "## Title\n\n### Section\n\n###### Deep\n");
+
+        doc.shift_headers(-3);
+        assert_eq!(doc.render(), "# Title\n\n# Section\n\n### Deep\n");
+    }
+
+    #[test]
+    fn test_document_shift_headers_nested() {
+        let mut doc = Markdown::new();
+        doc.quote(Quote::with(vec![Header::from("Quoted", 1usize)]));
+
+        doc.shift_headers(2);
+        assert_eq!(doc.render(), "> ### Quoted\n");
+    }
+
+    #[test]
+    fn test_document_shift_headers_demote() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").h6("Deep");
+
+        doc.shift_headers_with(1, HeaderOverflow::Demote).unwrap();
+        assert_eq!(doc.render(), "## Title\n\n**Deep**\n");
+    }
+
+    #[test]
+    fn test_document_shift_sections_demote() {
+        let mut doc = Markdown::new();
+        doc.section("Title", |s| {
+            s.paragraph("Intro").section("Usage", |s| {
+                s.paragraph("Run it.");
+            });
+        });
+
+        doc.shift_headers_with(5, HeaderOverflow::Demote).unwrap();
+        assert_eq!(
+            doc.render(),
+            "###### Title\n\nIntro\n\n**Usage**\n\nRun it.\n"
+        );
+        assert_eq!(doc.headers().count(), 1);
+
+        doc.shift_headers_with(1, HeaderOverflow::Demote).unwrap();
+        assert_eq!(doc.render(), "**Title**\n\nIntro\n\n**Usage**\n\nRun it.\n");
+        assert_eq!(doc.headers().count(), 0);
+    }
+
+    #[test]
+    fn test_document_shift_headers_error() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").h6("Deep");
+
+        let error = doc
+            .shift_headers_with(1, HeaderOverflow::Error)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            HeaderLevelError {
+                text: "Deep".into(),
+                level: 7
+            }
+        );
+        assert_eq!(doc.render(), "# Title\n\n###### Deep\n");
+    }
+
+    #[test]
+    fn test_document_embed() {
+        let mut chapter = Markdown::new();
+        chapter.h1("Install").paragraph("Run it.").h2("Linux");
+
+        let mut doc = Markdown::new();
+        doc.h1("Handbook").h2("Guides").embed(chapter, 3usize);
+
+        assert_eq!(
+            doc.render(),
+            "# Handbook\n\n## Guides\n\n### Install\n\nRun it.\n\n#### Linux\n"
+        );
+    }
+
+    #[test]
+    fn test_document_embed_without_headers() {
+        let mut other = Markdown::new();
+        other.paragraph("Text");
+
+        let mut doc = Markdown::new();
+        doc.embed(other, 4usize);
+
+        assert_eq!(doc.render(), "Text\n");
+    }
+
+    #[test]
+    fn test_document_embed_overflow() {
+        let mut chapter = Markdown::new();
+        chapter.h1("Install").h3("Details");
+
+        let mut doc = Markdown::new();
+        assert!(doc
+            .embed_with(chapter.clone(), 5usize, HeaderOverflow::Error)
+            .is_err());
+        assert!(doc.elements.is_empty());
+
+        doc.embed_with(chapter, 5usize, HeaderOverflow::Demote)
+            .unwrap();
+        assert_eq!(doc.render(), "##### Install\n\n**Details**\n");
+    }
+
+    fn footer_link(url: &str, text: &str) -> Link {
+        LinkBuilder::new().url(url).text(text).footer().build()
+    }
+
+    #[test]
+    fn test_document_append() {
+        let mut other = Markdown::new();
+        other.h2("Other").paragraph("More text");
+
+        let mut doc = Markdown::new();
+        doc.h1("Title").append(other);
+
+        assert_eq!(doc.render(), "# Title\n\n## Other\n\nMore text\n");
+    }
+
+    #[test]
+    fn test_document_append_deduplicates_footers() {
+        let mut other = Markdown::new();
+        other.link(footer_link("https://example.com", "Example"));
+        other.footers.push("[rust]: https://rust-lang.org".into());
+
+        let mut doc = Markdown::new();
+        doc.link(footer_link("https://example.com", "Example"));
+        doc.footers.push("[rust]: https://rust-lang.org".into());
+        doc.append(other);
+
+        assert_eq!(doc.footers.len(), 1);
+        assert_eq!(
+            doc.collect_footers(),
+            vec![
+                "[Example]: https://example.com",
+                "[rust]: https://rust-lang.org"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_append_renames_conflicting_footers() {
+        let mut other = Markdown::new();
+        other
+            .link(footer_link("https://docs.rs", "docs"))
+            .link(footer_link("https://docs.rs", "Docs"))
+            .link(footer_link("https://example.com", "Example"));
+
+        let mut doc = Markdown::new();
+        doc.link(footer_link("https://example.com/docs", "docs"))
+            .link(footer_link("https://example.com", "Example"))
+            .append(other);
+
+        assert_eq!(
+            doc.render(),
+            "[docs][docs]\n\n[Example][Example]\n\n[docs][docs-2]\n\n[Docs][docs-2]\n\n[Example][Example]\n\n\
+            [docs]: https://example.com/docs\n[Example]: https://example.com\n[docs-2]: https://docs.rs\n"
+        );
+    }
+
+    #[test]
+    fn test_document_append_renames_conflicting_explicit_footers() {
+        let mut other = Markdown::new();
+        other.footers.push("[rust]: https://example.com".into());
+
+        let mut doc = Markdown::new();
+        doc.footers.push("[rust]: https://rust-lang.org".into());
+        doc.append(other);
+
+        assert_eq!(
+            doc.collect_footers(),
+            vec![
+                "[rust]: https://rust-lang.org",
+                "[rust-2]: https://example.com"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_extend() {
+        let mut first = Markdown::new();
+        first.paragraph("One");
+        let mut second = Markdown::new();
+        second.paragraph("Two");
+
+        let mut doc = Markdown::new();
+        doc.extend(vec![first, second]);
+        doc.extend(vec![Node::from(Paragraph::from("Three"))]);
+
+        assert_eq!(doc.render(), "One\n\nTwo\n\nThree\n");
+    }
+
+    #[test]
+    fn test_document_include() {
+        let mut other = Markdown::new();
+        other
+            .paragraph("Included")
+            .link(footer_link("https://docs.rs", "docs"));
+        other.footers.push("[rust]: https://rust-lang.org".into());
+
+        let mut doc = Markdown::new();
+        doc.link(footer_link("https://example.com/docs", "docs"))
+            .include(other)
+            .paragraph("After");
+
+        assert!(matches!(doc.elements[1], Node::Include(_)));
+        assert_eq!(
+            doc.render(),
+            "[docs][docs]\n\nIncluded\n\n[docs][docs-2]\n\nAfter\n\n\
+            [docs]: https://example.com/docs\n[docs-2]: https://docs.rs\n[rust]: https://rust-lang.org\n"
+        );
+    }
+
+    #[test]
+    fn test_document_with_list() {
+        let mut doc = Markdown::new();
+
+        doc.list(
+            ListBuilder::new()
+                .append("First do this")
+                .append("Then do this")
+                .ordered(),
+        );
 
         assert_eq!(doc.render(), "1. First do this\n2. Then do this\n")
     }
@@ -417,4 +1525,231 @@ mod tests {
             "Hello world\n"
         );
     }
+
+    #[test]
+    fn test_document_add_keeps_built_in_type() {
+        let mut doc = Markdown::new();
+        doc.add(Paragraph::from("Hello world"));
+        assert!(matches!(doc.elements[0], Node::Paragraph(_)));
+    }
+
+    #[test]
+    fn test_document_headers() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").paragraph("Text").h2("Section");
+
+        let headers: Vec<&str> = doc.headers().map(|header| header.text.as_str()).collect();
+        assert_eq!(headers, vec!["Title", "Section"]);
+    }
+
+    #[test]
+    fn test_document_links_include_nested() {
+        let mut doc = Markdown::new();
+        doc.link(
+            LinkBuilder::new()
+                .url("https://example.com")
+                .text("Top")
+                .build(),
+        )
+        .list(
+            ListBuilder::new()
+                .append(
+                    LinkBuilder::new()
+                        .url("https://example.com/nested")
+                        .text("Nested")
+                        .inlined()
+                        .build(),
+                )
+                .unordered(),
+        );
+
+        let urls: Vec<&str> = doc.links().map(|link| link.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec!["https://example.com", "https://example.com/nested"]
+        );
+    }
+
+    #[test]
+    fn test_document_iter_mut() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").paragraph("Text");
+
+        for node in doc.iter_mut() {
+            if let Node::Header(header) = node {
+                header.text = header.text.to_uppercase();
+            }
+        }
+
+        assert_eq!(doc.render(), "# TITLE\n\nText\n");
+    }
+
+    #[test]
+    fn test_document_nodes_order() {
+        let mut doc = Markdown::new();
+        doc.list(ListBuilder::new().append("a").append("b").unordered())
+            .paragraph("c");
+
+        let rendered: Vec<String> = doc.nodes().map(|node| node.render()).collect();
+        assert_eq!(rendered, vec!["- a\n- b\n", "a", "b", "c\n", "c"]);
+    }
+
+    fn handbook() -> Markdown {
+        let mut doc = Markdown::new();
+        doc.section("Install", |s| {
+            s.paragraph("Pick your platform.").section("Linux", |s| {
+                s.link(
+                    LinkBuilder::new()
+                        .url("https://archlinux.org")
+                        .text("Arch")
+                        .footer()
+                        .build(),
+                );
+            });
+        })
+        .section("Usage", |s| {
+            s.paragraph("Run it.");
+        });
+        doc
+    }
+
+    #[test]
+    fn test_document_sections() {
+        assert_eq!(
+            handbook().render(),
+            "# Install\n\nPick your platform.\n\n## Linux\n\n[Arch][Arch]\n\n\
+            # Usage\n\nRun it.\n\n[Arch]: https://archlinux.org\n"
+        );
+    }
+
+    #[test]
+    fn test_document_section_mut() {
+        let mut doc = handbook();
+        doc.section_mut(["Install", "Linux"])
+            .unwrap()
+            .paragraph("Use your package manager.")
+            .section("Arch", |_| {});
+
+        let linux = doc.find_section(["Install", "Linux"]).unwrap();
+        assert_eq!(linux.children.len(), 3);
+        assert_eq!(linux.find_section(["Arch"]).unwrap().level().value(), 3);
+        assert!(doc.section_mut(["Linux"]).is_none());
+        assert!(doc.find_section(Vec::<&str>::new()).is_none());
+    }
+
+    #[test]
+    fn test_shift_headers_includes_sections() {
+        let mut doc = handbook();
+        doc.shift_headers(1);
+
+        let levels: Vec<usize> = doc.headers().map(|header| header.level.value()).collect();
+        assert_eq!(levels, vec![2, 3, 2]);
+    }
+
+    #[test]
+    fn test_document_flavor() {
+        let mut glossary = DefinitionList::new();
+        glossary.term("AST", vec!["Abstract syntax tree."]);
+
+        let mut doc = Markdown::new();
+        doc.definition_list(glossary.clone())
+            .quote(Quote::with(vec![Node::from(glossary)]));
+        assert_eq!(
+            doc.render(),
+            "**AST**\n\nAbstract syntax tree.\n\n> **AST**\n>\n> Abstract syntax tree.\n"
+        );
+
+        doc.set_flavor(Flavor::Pandoc);
+        assert_eq!(
+            doc.render(),
+            "AST\n:   Abstract syntax tree.\n\n> AST\n> :   Abstract syntax tree.\n"
+        );
+    }
+
+    #[test]
+    fn test_document_details() {
+        let mut doc = Markdown::new();
+        doc.paragraph("Tests failed.").details("Logs", |details| {
+            details.set_open(true).paragraph("1 failed");
+        });
+
+        assert_eq!(
+            doc.render(),
+            "Tests failed.\n\n<details open>\n<summary>Logs</summary>\n\n1 failed\n\n</details>\n"
+        );
+    }
+
+    #[test]
+    fn test_document_math() {
+        let mut doc = Markdown::new();
+        doc.paragraph("Costs $3.").math("a^2 + b^2 = c^2");
+
+        assert_eq!(doc.render(), "Costs $3.\n\n$$\na^2 + b^2 = c^2\n$$\n");
+
+        doc.set_flavor(Flavor::GitHub);
+        assert_eq!(
+            doc.render(),
+            "Costs \\$3.\n\n```math\na^2 + b^2 = c^2\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_document_linked_image_footers() {
+        let mut doc = Markdown::new();
+        doc.image(
+            ImageBuilder::new()
+                .url("ci.svg")
+                .text("CI")
+                .linked_to("https://ci.example.com")
+                .footer()
+                .build(),
+        );
+
+        assert_eq!(
+            doc.render(),
+            "[![CI][CI]][CI-link]\n\n[CI]: ci.svg\n[CI-link]: https://ci.example.com\n"
+        );
+    }
+
+    #[test]
+    fn test_document_badges() {
+        let mut doc = Markdown::new();
+        doc.badges(vec![
+            Badge::from("ci", "passing", "green"),
+            Badge::from("", "MIT", "blue"),
+        ])
+        .header("markdown-builder", 1usize);
+
+        assert_eq!(
+            doc.render(),
+            "![ci: passing](https://img.shields.io/badge/ci-passing-green)\n\
+            ![MIT](https://img.shields.io/badge/MIT-blue)\n\n# markdown-builder\n"
+        );
+        assert_eq!(doc.images().count(), 2);
+    }
+
+    #[test]
+    fn test_document_inline_footers() {
+        let mut paragraph = Paragraph::new();
+        paragraph
+            .append("Read ")
+            .link(
+                LinkBuilder::new()
+                    .url("https://docs.rs")
+                    .text("the docs")
+                    .footer()
+                    .inlined()
+                    .build(),
+            )
+            .append(".");
+
+        let mut doc = Markdown::new();
+        doc.add(paragraph);
+
+        assert_eq!(doc.links().count(), 1);
+        assert_eq!(
+            doc.render(),
+            "Read [the docs][the docs].\n\n[the docs]: https://docs.rs\n"
+        );
+    }
 }
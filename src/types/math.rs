@@ -0,0 +1,174 @@
+use crate::{flavor::Flavor, transforms::CodeBlock};
+use std::fmt;
+
+/// The syntax used to delimit math.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum MathSyntax {
+    /// `$$ ... $$` blocks and `$...$` spans, e.g. for Pandoc.
+    #[default]
+    Dollars,
+    /// ```` ```math ```` blocks and `` $`...`$ `` spans, used by GitHub and
+    /// GitLab.
+    Fenced,
+    /// `\\[ ... \\]` blocks and `\\(...\\)` spans, which render as `\[ ... \]`
+    /// and `\(...\)` for KaTeX and MathJax once markdown unescapes them.
+    Latex,
+}
+
+impl MathSyntax {
+    /// Returns the syntax to use, preferring `syntax` over the flavor's.
+    fn resolve(syntax: Option<Self>, flavor: Flavor) -> Self {
+        syntax.or(flavor.math_syntax()).unwrap_or_default()
+    }
+}
+
+/// A display math block, e.g. a formula on its own line.
+///
+/// The syntax is chosen by the flavor the block is rendered for, unless set
+/// using [set_syntax](struct.MathBlock.html#method.set_syntax).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MathBlock {
+    /// The TeX source of the formula.
+    pub tex: String,
+    /// The syntax overriding the flavor's one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub syntax: Option<MathSyntax>,
+}
+
+impl MathBlock {
+    /// Creates a new `MathBlock` with the given TeX source.
+    pub fn from(tex: impl Into<String>) -> Self {
+        Self {
+            tex: tex.into(),
+            syntax: None,
+        }
+    }
+
+    /// Sets the syntax to use regardless of the flavor.
+    pub fn set_syntax(&mut self, syntax: MathSyntax) -> &mut Self {
+        self.syntax = Some(syntax);
+        self
+    }
+
+    /// Renders the block for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        let tex = self.tex.trim_matches('\n');
+
+        match MathSyntax::resolve(self.syntax, flavor) {
+            MathSyntax::Dollars => format!("$$\n{}\n$$\n", tex),
+            MathSyntax::Fenced => format!("{}\n", tex.to_code_block_with_language("math")),
+            MathSyntax::Latex => format!("\\\\[\n{}\n\\\\]\n", tex),
+        }
+    }
+}
+
+impl fmt::Display for MathBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
+/// An inline math span, e.g. a variable inside of a sentence.
+///
+/// The syntax is chosen by the flavor the span is rendered for, unless set
+/// using [set_syntax](struct.InlineMath.html#method.set_syntax).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InlineMath {
+    /// The TeX source of the formula.
+    pub tex: String,
+    /// The syntax overriding the flavor's one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub syntax: Option<MathSyntax>,
+}
+
+impl InlineMath {
+    /// Creates a new `InlineMath` with the given TeX source.
+    pub fn from(tex: impl Into<String>) -> Self {
+        Self {
+            tex: tex.into(),
+            syntax: None,
+        }
+    }
+
+    /// Sets the syntax to use regardless of the flavor.
+    pub fn set_syntax(&mut self, syntax: MathSyntax) -> &mut Self {
+        self.syntax = Some(syntax);
+        self
+    }
+
+    /// Renders the span for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        match MathSyntax::resolve(self.syntax, flavor) {
+            MathSyntax::Dollars => format!("${}$", self.tex),
+            MathSyntax::Fenced => format!("$`{}`$", self.tex),
+            MathSyntax::Latex => format!("\\\\({}\\\\)", self.tex),
+        }
+    }
+}
+
+impl fmt::Display for InlineMath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Markdown, MarkdownElement, Paragraph};
+
+    #[test]
+    fn test_math_block_per_flavor() {
+        let block = MathBlock::from("e^{i\\pi} + 1 = 0");
+
+        assert_eq!(block.render(), "$$\ne^{i\\pi} + 1 = 0\n$$\n");
+        assert_eq!(
+            block.render_with(Flavor::GitHub),
+            "```math\ne^{i\\pi} + 1 = 0\n```\n"
+        );
+        assert_eq!(
+            block.render_with(Flavor::Pandoc),
+            "$$\ne^{i\\pi} + 1 = 0\n$$\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_math_per_flavor() {
+        let math = InlineMath::from("x^2");
+
+        assert_eq!(math.render(), "$x^2$");
+        assert_eq!(math.render_with(Flavor::GitLab), "$`x^2`$");
+    }
+
+    #[test]
+    fn test_syntax_override() {
+        let mut block = MathBlock::from("a");
+        block.set_syntax(MathSyntax::Latex);
+        let mut math = InlineMath::from("b");
+        math.set_syntax(MathSyntax::Latex);
+
+        assert_eq!(block.render_with(Flavor::GitHub), "\\\\[\na\n\\\\]\n");
+        assert_eq!(math.render_with(Flavor::GitHub), "\\\\(b\\\\)");
+    }
+
+    #[test]
+    fn test_latex_in_document() {
+        let mut math = InlineMath::from("x");
+        math.set_syntax(MathSyntax::Latex);
+        let mut block = MathBlock::from("x^2");
+        block.set_syntax(MathSyntax::Latex);
+        let mut paragraph = Paragraph::from("Let ");
+        paragraph.add(math);
+        let mut doc = Markdown::new();
+        doc.set_flavor(Flavor::CommonMark).add(paragraph).add(block);
+
+        assert_eq!(doc.render(), "Let \\\\(x\\\\)\n\n\\\\[\nx^2\n\\\\]\n");
+    }
+}
@@ -1,10 +1,23 @@
 //! This module defines all Markdown types that are specified inside the
 //! CommonMark specification.
 
+pub mod autolink;
+pub mod badge;
 pub mod checkbox;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod definition_list;
+pub mod details;
+pub mod diagram;
 pub mod header;
 pub mod image;
+pub mod include;
 pub mod link;
 pub mod list;
 pub mod markdown;
+pub mod math;
+pub mod node;
 pub mod paragraph;
+pub mod quote;
+pub mod section;
+pub mod table;
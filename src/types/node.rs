@@ -0,0 +1,543 @@
+use crate::{
+    flavor::Flavor,
+    traits::MarkdownElement,
+    types::{
+        autolink::Autolink,
+        checkbox::{Checkbox, Task},
+        definition_list::DefinitionList,
+        details::Details,
+        diagram::Diagram,
+        header::Header,
+        image::Image,
+        include::Include,
+        link::Link,
+        list::List,
+        math::{InlineMath, MathBlock},
+        paragraph::Paragraph,
+        quote::Quote,
+        section::Section,
+        table::Table,
+    },
+};
+use std::{any::Any, fmt};
+
+/// A node inside a markdown document.
+///
+/// Built-in elements are stored as their own variant so they can be inspected
+/// and modified after they have been added to a document. Every other element
+/// is stored inside of [Custom](enum.Node.html#variant.Custom).
+#[derive(Clone, Debug)]
+pub enum Node {
+    /// A [Header](../header/struct.Header.html).
+    Header(Header),
+    /// A [Paragraph](../paragraph/struct.Paragraph.html).
+    Paragraph(Paragraph),
+    /// A [Link](../link/struct.Link.html).
+    Link(Link),
+    /// An [Image](../image/struct.Image.html).
+    Image(Image),
+    /// A [List](../list/struct.List.html).
+    List(List),
+    /// A [Checkbox](../checkbox/enum.Checkbox.html).
+    Checkbox(Checkbox),
+    /// A [Task](../checkbox/struct.Task.html), a checkbox with subtasks.
+    Task(Task),
+    /// A [Quote](../quote/struct.Quote.html).
+    Quote(Quote),
+    /// An [Include](../include/struct.Include.html).
+    Include(Include),
+    /// A [Section](../section/struct.Section.html).
+    Section(Section),
+    /// A [Table](../table/struct.Table.html).
+    Table(Table),
+    /// A [DefinitionList](../definition_list/struct.DefinitionList.html).
+    DefinitionList(DefinitionList),
+    /// A [Details](../details/struct.Details.html).
+    Details(Details),
+    /// A [MathBlock](../math/struct.MathBlock.html).
+    MathBlock(MathBlock),
+    /// An [InlineMath](../math/struct.InlineMath.html) span.
+    InlineMath(InlineMath),
+    /// A [Diagram](../diagram/struct.Diagram.html).
+    Diagram(Diagram),
+    /// An [Autolink](../autolink/struct.Autolink.html).
+    Autolink(Autolink),
+    /// Raw markdown text, rendered as-is.
+    Text(String),
+    /// Any other markdown element.
+    Custom(Box<dyn MarkdownElement>),
+}
+
+impl Node {
+    /// Creates a new `Node` from any markdown element.
+    ///
+    /// Built-in elements are stored using their own variant, strings are stored
+    /// as [Text](enum.Node.html#variant.Text) and all other elements as
+    /// [Custom](enum.Node.html#variant.Custom).
+    pub fn from_element<T>(element: T) -> Self
+    where
+        T: MarkdownElement + 'static,
+    {
+        // Wrapping the element inside of an `Option` allows moving it out of
+        // the downcast reference, avoiding a clone.
+        let mut slot = Some(element);
+        let any = &mut slot as &mut dyn Any;
+
+        if let Some(node) = any.downcast_mut::<Option<Node>>() {
+            node.take()
+        } else if let Some(header) = any.downcast_mut::<Option<Header>>() {
+            header.take().map(Self::Header)
+        } else if let Some(paragraph) = any.downcast_mut::<Option<Paragraph>>() {
+            paragraph.take().map(Self::Paragraph)
+        } else if let Some(link) = any.downcast_mut::<Option<Link>>() {
+            link.take().map(Self::Link)
+        } else if let Some(image) = any.downcast_mut::<Option<Image>>() {
+            image.take().map(Self::Image)
+        } else if let Some(list) = any.downcast_mut::<Option<List>>() {
+            list.take().map(Self::List)
+        } else if let Some(checkbox) = any.downcast_mut::<Option<Checkbox>>() {
+            checkbox.take().map(Self::Checkbox)
+        } else if let Some(task) = any.downcast_mut::<Option<Task>>() {
+            task.take().map(Self::Task)
+        } else if let Some(quote) = any.downcast_mut::<Option<Quote>>() {
+            quote.take().map(Self::Quote)
+        } else if let Some(include) = any.downcast_mut::<Option<Include>>() {
+            include.take().map(Self::Include)
+        } else if let Some(section) = any.downcast_mut::<Option<Section>>() {
+            section.take().map(Self::Section)
+        } else if let Some(table) = any.downcast_mut::<Option<Table>>() {
+            table.take().map(Self::Table)
+        } else if let Some(list) = any.downcast_mut::<Option<DefinitionList>>() {
+            list.take().map(Self::DefinitionList)
+        } else if let Some(details) = any.downcast_mut::<Option<Details>>() {
+            details.take().map(Self::Details)
+        } else if let Some(math) = any.downcast_mut::<Option<MathBlock>>() {
+            math.take().map(Self::MathBlock)
+        } else if let Some(math) = any.downcast_mut::<Option<InlineMath>>() {
+            math.take().map(Self::InlineMath)
+        } else if let Some(diagram) = any.downcast_mut::<Option<Diagram>>() {
+            diagram.take().map(Self::Diagram)
+        } else if let Some(link) = any.downcast_mut::<Option<Autolink>>() {
+            link.take().map(Self::Autolink)
+        } else if let Some(text) = any.downcast_mut::<Option<String>>() {
+            text.take().map(Self::Text)
+        } else if let Some(text) = any.downcast_mut::<Option<&'static str>>() {
+            text.take().map(|text| Self::Text(text.to_string()))
+        } else {
+            slot.map(|element| Self::Custom(Box::new(element)))
+        }
+        .expect("element is only taken once")
+    }
+
+    /// Returns an iterator over the child nodes, e.g. the items of a list.
+    pub fn children(&self) -> Children<'_> {
+        let slices: Vec<&[Node]> = match self {
+            Self::Paragraph(paragraph) => vec![&paragraph.children],
+            Self::List(list) => vec![&list.items],
+            Self::Task(task) => vec![&task.subtasks.items],
+            Self::Quote(quote) => vec![&quote.children],
+            Self::Include(include) => vec![&include.document.elements],
+            Self::Section(section) => vec![&section.children],
+            Self::Details(details) => vec![&details.children],
+            Self::DefinitionList(list) => list
+                .items
+                .iter()
+                .flat_map(|item| item.definitions.iter().map(Vec::as_slice))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Children(slices.into_iter().flatten())
+    }
+
+    /// Returns an iterator that allows modifying the child nodes.
+    pub fn children_mut(&mut self) -> impl Iterator<Item = &mut Node> {
+        let slices: Vec<&mut [Node]> = match self {
+            Self::Paragraph(paragraph) => vec![&mut paragraph.children],
+            Self::List(list) => vec![&mut list.items],
+            Self::Task(task) => vec![&mut task.subtasks.items],
+            Self::Quote(quote) => vec![&mut quote.children],
+            Self::Include(include) => vec![&mut include.document.elements],
+            Self::Section(section) => vec![&mut section.children],
+            Self::Details(details) => vec![&mut details.children],
+            Self::DefinitionList(list) => list
+                .items
+                .iter_mut()
+                .flat_map(|item| item.definitions.iter_mut().map(Vec::as_mut_slice))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        slices.into_iter().flatten()
+    }
+
+    /// Renders the node for the given flavor, separating the blocks inside of
+    /// includes, sections, quotes and details like a
+    /// [compact](../markdown/struct.Markdown.html#structfield.compact)
+    /// document if `compact` is set.
+    pub(crate) fn render_block(&self, flavor: Flavor, compact: bool) -> String {
+        match self {
+            Self::Quote(quote) => quote.render_blocks(flavor, compact),
+            Self::Include(include) => include.render_blocks(flavor, compact),
+            Self::Section(section) => section.render_blocks(flavor, compact),
+            Self::Details(details) => details.render_blocks(flavor, compact),
+            node => node.render_with(flavor),
+        }
+    }
+
+    /// Renders the node for the given flavor.
+    ///
+    /// Elements without flavor specific syntax render the same as
+    /// [render](../../traits/trait.MarkdownElement.html#tymethod.render).
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        match self {
+            Self::Header(header) => header.render_with(flavor),
+            Self::Paragraph(paragraph) => paragraph.render_with(flavor),
+            Self::Image(image) => image.render_with(flavor),
+            Self::List(list) => list.render_with(flavor),
+            Self::Task(task) => task.render_with(flavor),
+            Self::Quote(quote) => quote.render_with(flavor),
+            Self::Include(include) => include.render_with(flavor),
+            Self::Section(section) => section.render_with(flavor),
+            Self::DefinitionList(list) => list.render_with(flavor),
+            Self::Details(details) => details.render_with(flavor),
+            Self::MathBlock(math) => math.render_with(flavor),
+            Self::InlineMath(math) => math.render_with(flavor),
+            Self::Autolink(link) => link.render_with(flavor),
+            _ => self.render(),
+        }
+    }
+}
+
+/// An iterator over the direct children of a node.
+///
+/// Created by [Node::children](enum.Node.html#method.children).
+#[derive(Clone, Debug)]
+pub struct Children<'a>(std::iter::Flatten<std::vec::IntoIter<&'a [Node]>>);
+
+impl<'a> Iterator for Children<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// An iterator over nodes and all of their descendants, in document order.
+///
+/// Created by [Markdown::nodes](../markdown/struct.Markdown.html#method.nodes).
+#[derive(Clone, Debug)]
+pub struct Nodes<'a> {
+    stack: Vec<Children<'a>>,
+}
+
+impl<'a> Nodes<'a> {
+    pub(crate) fn new(nodes: &'a [Node]) -> Self {
+        Self {
+            stack: vec![Children(vec![nodes].into_iter().flatten())],
+        }
+    }
+}
+
+impl<'a> Iterator for Nodes<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(iter) = self.stack.last_mut() {
+            if let Some(node) = iter.next() {
+                self.stack.push(node.children());
+                return Some(node);
+            }
+            self.stack.pop();
+        }
+
+        None
+    }
+}
+
+impl From<Box<dyn MarkdownElement>> for Node {
+    /// Converts a boxed element, unboxing it if it is a built-in element.
+    fn from(value: Box<dyn MarkdownElement>) -> Self {
+        let Some(any) = value.as_any() else {
+            return Self::Custom(value);
+        };
+
+        if let Some(node) = any.downcast_ref::<Node>() {
+            node.clone()
+        } else if let Some(header) = any.downcast_ref::<Header>() {
+            Self::Header(header.clone())
+        } else if let Some(paragraph) = any.downcast_ref::<Paragraph>() {
+            Self::Paragraph(paragraph.clone())
+        } else if let Some(link) = any.downcast_ref::<Link>() {
+            Self::Link(link.clone())
+        } else if let Some(image) = any.downcast_ref::<Image>() {
+            Self::Image(image.clone())
+        } else if let Some(list) = any.downcast_ref::<List>() {
+            Self::List(list.clone())
+        } else if let Some(checkbox) = any.downcast_ref::<Checkbox>() {
+            Self::Checkbox(checkbox.clone())
+        } else if let Some(task) = any.downcast_ref::<Task>() {
+            Self::Task(task.clone())
+        } else if let Some(quote) = any.downcast_ref::<Quote>() {
+            Self::Quote(quote.clone())
+        } else if let Some(include) = any.downcast_ref::<Include>() {
+            Self::Include(include.clone())
+        } else if let Some(section) = any.downcast_ref::<Section>() {
+            Self::Section(section.clone())
+        } else if let Some(table) = any.downcast_ref::<Table>() {
+            Self::Table(table.clone())
+        } else if let Some(list) = any.downcast_ref::<DefinitionList>() {
+            Self::DefinitionList(list.clone())
+        } else if let Some(details) = any.downcast_ref::<Details>() {
+            Self::Details(details.clone())
+        } else if let Some(math) = any.downcast_ref::<MathBlock>() {
+            Self::MathBlock(math.clone())
+        } else if let Some(math) = any.downcast_ref::<InlineMath>() {
+            Self::InlineMath(math.clone())
+        } else if let Some(diagram) = any.downcast_ref::<Diagram>() {
+            Self::Diagram(diagram.clone())
+        } else if let Some(link) = any.downcast_ref::<Autolink>() {
+            Self::Autolink(link.clone())
+        } else if let Some(text) = any.downcast_ref::<String>() {
+            Self::Text(text.clone())
+        } else if let Some(text) = any.downcast_ref::<&'static str>() {
+            Self::Text(text.to_string())
+        } else {
+            Self::Custom(value)
+        }
+    }
+}
+
+impl<T> From<Box<T>> for Node
+where
+    T: MarkdownElement + 'static,
+{
+    fn from(value: Box<T>) -> Self {
+        Self::from_element(*value)
+    }
+}
+
+impl From<Header> for Node {
+    fn from(value: Header) -> Self {
+        Self::Header(value)
+    }
+}
+
+impl From<Paragraph> for Node {
+    fn from(value: Paragraph) -> Self {
+        Self::Paragraph(value)
+    }
+}
+
+impl From<Link> for Node {
+    fn from(value: Link) -> Self {
+        Self::Link(value)
+    }
+}
+
+impl From<Image> for Node {
+    fn from(value: Image) -> Self {
+        Self::Image(value)
+    }
+}
+
+impl From<List> for Node {
+    fn from(value: List) -> Self {
+        Self::List(value)
+    }
+}
+
+impl From<Checkbox> for Node {
+    fn from(value: Checkbox) -> Self {
+        Self::Checkbox(value)
+    }
+}
+
+impl From<Task> for Node {
+    fn from(value: Task) -> Self {
+        Self::Task(value)
+    }
+}
+
+impl From<Quote> for Node {
+    fn from(value: Quote) -> Self {
+        Self::Quote(value)
+    }
+}
+
+impl From<Include> for Node {
+    fn from(value: Include) -> Self {
+        Self::Include(value)
+    }
+}
+
+impl From<Section> for Node {
+    fn from(value: Section) -> Self {
+        Self::Section(value)
+    }
+}
+
+impl From<Table> for Node {
+    fn from(value: Table) -> Self {
+        Self::Table(value)
+    }
+}
+
+impl From<DefinitionList> for Node {
+    fn from(value: DefinitionList) -> Self {
+        Self::DefinitionList(value)
+    }
+}
+
+impl From<Details> for Node {
+    fn from(value: Details) -> Self {
+        Self::Details(value)
+    }
+}
+
+impl From<MathBlock> for Node {
+    fn from(value: MathBlock) -> Self {
+        Self::MathBlock(value)
+    }
+}
+
+impl From<InlineMath> for Node {
+    fn from(value: InlineMath) -> Self {
+        Self::InlineMath(value)
+    }
+}
+
+impl From<Diagram> for Node {
+    fn from(value: Diagram) -> Self {
+        Self::Diagram(value)
+    }
+}
+
+impl From<Autolink> for Node {
+    fn from(value: Autolink) -> Self {
+        Self::Autolink(value)
+    }
+}
+
+impl From<String> for Node {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Node {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Header(header) => header.fmt(f),
+            Self::Paragraph(paragraph) => paragraph.fmt(f),
+            Self::Link(link) => link.fmt(f),
+            Self::Image(image) => image.fmt(f),
+            Self::List(list) => list.fmt(f),
+            Self::Checkbox(checkbox) => checkbox.fmt(f),
+            Self::Task(task) => task.fmt(f),
+            Self::Quote(quote) => quote.fmt(f),
+            Self::Include(include) => include.fmt(f),
+            Self::Section(section) => section.fmt(f),
+            Self::Table(table) => table.fmt(f),
+            Self::DefinitionList(list) => list.fmt(f),
+            Self::Details(details) => details.fmt(f),
+            Self::MathBlock(math) => math.fmt(f),
+            Self::InlineMath(math) => math.fmt(f),
+            Self::Diagram(diagram) => diagram.fmt(f),
+            Self::Autolink(link) => link.fmt(f),
+            Self::Text(text) => f.write_str(text),
+            Self::Custom(element) => f.write_str(&element.render()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    struct TableOfContents;
+
+    impl fmt::Display for TableOfContents {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            writeln!(f, "[[toc]]")
+        }
+    }
+
+    #[test]
+    fn test_from_element_built_in() {
+        assert!(matches!(
+            Node::from_element(Header::from("Title", 1usize)),
+            Node::Header(_)
+        ));
+        assert!(matches!(
+            Node::from_element(Paragraph::from("Hello")),
+            Node::Paragraph(_)
+        ));
+        assert!(matches!(Node::from_element(List::new()), Node::List(_)));
+    }
+
+    #[test]
+    fn test_from_element_text() {
+        assert!(matches!(Node::from_element("Hello"), Node::Text(text) if text == "Hello"));
+        assert!(matches!(
+            Node::from_element(String::from("Hello")),
+            Node::Text(text) if text == "Hello"
+        ));
+    }
+
+    #[test]
+    fn test_from_element_custom() {
+        let node = Node::from_element(TableOfContents);
+        assert!(matches!(node, Node::Custom(_)));
+        assert_eq!(node.render(), "[[toc]]\n");
+    }
+
+    #[test]
+    fn test_from_element_node_is_kept() {
+        let node = Node::from_element(Node::from(Paragraph::from("Hello")));
+        assert!(matches!(node, Node::Paragraph(_)));
+    }
+
+    #[test]
+    fn test_from_boxed_element() {
+        let boxed: Box<dyn MarkdownElement> = Box::new(Paragraph::from("Hello"));
+        assert!(matches!(Node::from(boxed), Node::Paragraph(_)));
+
+        let boxed: Box<dyn MarkdownElement> = Box::new(TableOfContents);
+        assert!(matches!(Node::from(boxed), Node::Custom(_)));
+    }
+
+    #[test]
+    fn test_from_manual_element() {
+        #[derive(Clone, Debug)]
+        struct Rule;
+
+        impl MarkdownElement for Rule {
+            fn render(&self) -> String {
+                "---\n".to_string()
+            }
+        }
+
+        let boxed: Box<dyn MarkdownElement> = Box::new(Rule);
+        let node = Node::from(boxed);
+        assert!(matches!(node, Node::Custom(_)));
+        assert_eq!(node.render(), "---\n");
+
+        let text = String::from("borrowed");
+        let borrowed: Box<dyn MarkdownElement + '_> = text.as_str().into();
+        assert_eq!(borrowed.render(), "borrowed");
+    }
+
+    #[test]
+    fn test_display_matches_element() {
+        let paragraph = Paragraph::from("Hello");
+        assert_eq!(Node::from(paragraph.clone()).render(), paragraph.render());
+    }
+}
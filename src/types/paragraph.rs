@@ -1,63 +1,321 @@
+use crate::{
+    flavor::Flavor,
+    traits::MarkdownElement,
+    types::{image::Image, link::Link, node::Node},
+};
 use std::fmt;
 
 /// A markdown paragraph.
 ///
 /// A paragraph is a continuous text that is visually separated from its
 /// surrounding markdown elements. Word wrapped at 80 characters.
-
-#[derive(Clone, Debug)]
+///
+/// Besides text, a paragraph can hold inline elements such as links and
+/// images. Their footers are collected by the document like the ones of
+/// top-level elements.
+///
+/// In flavors [rendering math](../../flavor/enum.Flavor.html#method.supports_math),
+/// dollar signs outside of code spans are escaped so they are not mistaken
+/// for math.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "ParagraphDef", into = "ParagraphDef")
+)]
 pub struct Paragraph {
-    /// The text inside the paragraph.
-    pub text: String,
+    /// The text and inline elements, rendered next to each other.
+    pub children: Vec<Node>,
+}
+
+/// The serialized form of a [Paragraph], keeping the `text` field for
+/// paragraphs without inline elements.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum ParagraphDef {
+    Text { text: String },
+    Children { children: Vec<Node> },
+}
+
+#[cfg(feature = "serde")]
+impl From<ParagraphDef> for Paragraph {
+    fn from(value: ParagraphDef) -> Self {
+        match value {
+            ParagraphDef::Text { text } => Self::from(text),
+            ParagraphDef::Children { children } => Self { children },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Paragraph> for ParagraphDef {
+    fn from(value: Paragraph) -> Self {
+        match value.children.as_slice() {
+            [Node::Text(text)] => Self::Text { text: text.clone() },
+            _ => Self::Children {
+                children: value.children,
+            },
+        }
+    }
 }
 
 impl Paragraph {
+    /// Creates a new empty paragraph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     /// Creates a new paragraph with the given text.
     pub fn from(text: impl Into<String>) -> Self {
-        Self { text: text.into() }
+        Self {
+            children: vec![Node::Text(text.into())],
+        }
     }
-}
 
-impl fmt::Display for Paragraph {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Creates a new paragraph with the given text and inline elements.
+    pub fn with(children: Vec<impl Into<Node>>) -> Self {
+        Self {
+            children: children.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the plain text of the paragraph, including the text of links
+    /// and images.
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .map(|child| match child {
+                Node::Text(text) => text.clone(),
+                Node::Link(link) => link.text.clone(),
+                Node::Image(image) => image.text.clone(),
+                child => child.render().trim_end_matches('\n').to_string(),
+            })
+            .collect()
+    }
+
+    /// Replaces the content of the paragraph with the given text, removing
+    /// all inline elements.
+    pub fn set_text(&mut self, text: impl Into<String>) -> &mut Self {
+        self.children = vec![Node::Text(text.into())];
+        self
+    }
+
+    /// Appends text to the paragraph.
+    pub fn append(&mut self, text: impl Into<String>) -> &mut Self {
+        self.children.push(Node::Text(text.into()));
+        self
+    }
+
+    /// Appends an inline link to the paragraph.
+    pub fn link(&mut self, link: Link) -> &mut Self {
+        self.children.push(Node::Link(link));
+        self
+    }
+
+    /// Appends an inline image to the paragraph.
+    pub fn image(&mut self, image: Image) -> &mut Self {
+        self.children.push(Node::Image(image));
+        self
+    }
+
+    /// Appends any generic inline markdown element to the paragraph.
+    pub fn add(&mut self, element: impl MarkdownElement + 'static) -> &mut Self {
+        self.children.push(Node::from_element(element));
+        self
+    }
+
+    /// Renders the paragraph for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        // Words are the units that are never split when wrapping. Inline
+        // elements are glued to the text around them, e.g. `[link](url).`.
+        let mut words: Vec<String> = Vec::new();
+        let mut glued = false;
+
+        for child in &self.children {
+            match child {
+                Node::Text(text) => {
+                    let text = if flavor.supports_math() {
+                        escape_dollars(text)
+                    } else {
+                        text.clone()
+                    };
+
+                    for (index, word) in text.split(' ').enumerate() {
+                        match words.last_mut() {
+                            Some(last) if index == 0 && glued => last.push_str(word),
+                            _ => words.push(word.to_string()),
+                        }
+                    }
+                    glued = true;
+                }
+                child => {
+                    let rendered = child.render_with(flavor);
+                    let rendered = rendered.trim_end_matches('\n');
+                    match words.last_mut() {
+                        Some(last) if glued => last.push_str(rendered),
+                        _ => words.push(rendered.to_string()),
+                    }
+                    glued = true;
+                }
+            }
+        }
+
+        let mut output = String::new();
         let mut current_size = 0;
 
-        for (index, word) in self.text.split(" ").enumerate() {
-            if current_size + word.len() > 80 {
+        for (index, word) in words.iter().enumerate() {
+            let width = word.chars().count();
+            if index != 0 && current_size + 1 + width > 80 {
                 current_size = 0;
-                write!(f, "\n{}", word)?;
-            } else {
-                current_size += word.len();
-                if index == 0 {
-                    write!(f, "{}", word,)?;
-                } else {
-                    write!(f, " {}", word,)?;
-                };
+                output.push('\n');
+            } else if index != 0 {
+                current_size += 1;
+                output.push(' ');
             }
+            current_size += width;
+            output.push_str(word);
         }
 
-        writeln!(f)?;
+        output.push('\n');
+        output
+    }
+}
 
-        Ok(())
+/// Escapes dollar signs, leaving code spans and the `` $`...`$ `` math syntax
+/// untouched.
+pub(crate) fn escape_dollars(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut output = String::with_capacity(text.len());
+    let mut index = 0;
+
+    while index < chars.len() {
+        match chars[index] {
+            '`' => {
+                let run = chars[index..].iter().take_while(|c| **c == '`').count();
+                let closing = (index + run..chars.len()).find(|&start| {
+                    chars[start..].iter().take_while(|c| **c == '`').count() == run
+                        && chars[start - 1] != '`'
+                });
+                let end = closing.map_or(index + run, |start| start + run);
+                output.extend(&chars[index..end]);
+                index = end;
+            }
+            '\\' if index + 1 < chars.len() => {
+                output.extend(&chars[index..index + 2]);
+                index += 2;
+            }
+            '$' => {
+                let touches_code =
+                    chars.get(index + 1) == Some(&'`') || (index > 0 && chars[index - 1] == '`');
+                if !touches_code {
+                    output.push('\\');
+                }
+                output.push('$');
+                index += 1;
+            }
+            c => {
+                output.push(c);
+                index += 1;
+            }
+        }
+    }
+
+    output
+}
+
+impl fmt::Display for Paragraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::InlineMath;
 
     #[test]
     fn test_display() {
         assert_eq!(Paragraph::from("Hello world").to_string(), "Hello world\n");
     }
 
+    #[test]
+    fn test_dollars_escaped_for_math_flavors() {
+        let paragraph = Paragraph::from("It costs $5 or `$HOME` and $`x`$ or \\$6");
+
+        assert_eq!(
+            paragraph.render_with(Flavor::GitHub),
+            "It costs \\$5 or `$HOME` and $`x`$ or \\$6\n"
+        );
+        assert_eq!(
+            paragraph.render_with(Flavor::CommonMark),
+            "It costs $5 or `$HOME` and $`x`$ or \\$6\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_elements() {
+        let mut paragraph = Paragraph::new();
+        paragraph
+            .append("See ")
+            .link(Link::from("https://docs.rs/a b", "the docs", false, false))
+            .append(" or ")
+            .image(Image::from("logo.png", "logo", false))
+            .append(".");
+
+        assert_eq!(
+            paragraph.render(),
+            "See [the docs](<https://docs.rs/a b>) or ![logo](logo.png).\n"
+        );
+        assert_eq!(paragraph.text(), "See the docs or logo.");
+    }
+
+    #[test]
+    fn test_set_text() {
+        let mut paragraph = Paragraph::with(vec![Node::Link(Link::from("a", "b", false, true))]);
+        paragraph.set_text("Hello");
+
+        assert_eq!(paragraph.text(), "Hello");
+        assert_eq!(paragraph.render(), "Hello\n");
+    }
+
+    #[test]
+    fn test_inline_elements_are_not_wrapped() {
+        let link = Link::from("https://example.com/some path", "a b", false, true);
+        let paragraph = Paragraph::with(vec![Node::Text("word ".repeat(16)), Node::Link(link)]);
+
+        assert_eq!(
+            paragraph.render().lines().last(),
+            Some("[a b](<https://example.com/some path>)")
+        );
+    }
+
+    #[test]
+    fn test_inline_math_is_not_escaped() {
+        let paragraph = Paragraph::with(vec![
+            Node::Text("Costs $5, ".into()),
+            Node::from(InlineMath::from("x^2")),
+        ]);
+
+        assert_eq!(paragraph.render_with(Flavor::Pandoc), "Costs \\$5, $x^2$\n");
+    }
+
+    #[test]
+    fn test_word_wrapping_width() {
+        let paragraph = Paragraph::from("abcd ".repeat(40));
+
+        assert!(paragraph.render().lines().all(|line| line.len() <= 80));
+        assert_eq!(paragraph.render().lines().next().map(str::len), Some(79));
+    }
+
     #[test]
     fn test_word_wrapping() {
         assert_eq!(
 			Paragraph::from(
 				"Markdown Builder is a Rustlang crate by Erb3, which lets you create markdown documents. It now supports word wrapping!"
 			).to_string(),
-			"Markdown Builder is a Rustlang crate by Erb3, which lets you create markdown documents. It now\nsupports word wrapping!\n"
+			"Markdown Builder is a Rustlang crate by Erb3, which lets you create markdown\ndocuments. It now supports word wrapping!\n"
 		);
     }
 }
@@ -0,0 +1,105 @@
+use crate::{
+    flavor::Flavor,
+    traits::MarkdownElement,
+    types::{markdown::join_blocks, node::Node},
+};
+use std::fmt;
+
+/// A markdown block quote.
+///
+/// Unlike the [BlockQuote](../../transforms/trait.BlockQuote.html)
+/// transformation, a `Quote` holds nodes, so it can contain any other element.
+/// The children are separated by a blank line, unless the quote is part of a
+/// [compact](../markdown/struct.Markdown.html#structfield.compact) document.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quote {
+    /// The elements inside the quote.
+    pub children: Vec<Node>,
+}
+
+impl Quote {
+    /// Creates a new empty `Quote`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `Quote` with the given children.
+    pub fn with(children: Vec<impl Into<Node>>) -> Self {
+        Self {
+            children: children.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Adds any generic markdown element to the quote.
+    pub fn add(&mut self, element: impl MarkdownElement + 'static) -> &mut Self {
+        self.children.push(Node::from_element(element));
+        self
+    }
+
+    /// Renders the quote for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        self.render_blocks(flavor, false)
+    }
+
+    /// Renders the quote, separating its blocks like a compact document if
+    /// `compact` is set.
+    pub(crate) fn render_blocks(&self, flavor: Flavor, compact: bool) -> String {
+        let blocks = self
+            .children
+            .iter()
+            .map(|child| child.render_block(flavor, compact));
+
+        join_blocks(blocks, compact)
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    ">\n".to_string()
+                } else {
+                    format!("> {}\n", line)
+                }
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Quote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ListBuilder, Paragraph};
+
+    #[test]
+    fn test_quote_single_paragraph() {
+        assert_eq!(
+            Quote::with(vec![Paragraph::from("Hello")]).render(),
+            "> Hello\n"
+        );
+    }
+
+    #[test]
+    fn test_quote_many_children() {
+        let mut quote = Quote::new();
+        quote
+            .add(Paragraph::from("Hello"))
+            .add(ListBuilder::new().append("a").append("b").unordered());
+
+        assert_eq!(quote.render(), "> Hello\n>\n> - a\n> - b\n");
+    }
+
+    #[test]
+    fn test_nested_quote() {
+        let quote = Quote::with(vec![Quote::with(vec![Paragraph::from("Hello")])]);
+        assert_eq!(quote.render(), "> > Hello\n");
+    }
+
+    #[test]
+    fn test_empty_quote() {
+        assert_eq!(Quote::new().render(), "");
+    }
+}
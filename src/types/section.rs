@@ -0,0 +1,311 @@
+use crate::{
+    flavor::Flavor,
+    traits::MarkdownElement,
+    types::{
+        header::{Header, HeaderLevel},
+        image::Image,
+        link::Link,
+        list::List,
+        markdown::join_blocks,
+        node::Node,
+        paragraph::Paragraph,
+        quote::Quote,
+    },
+};
+use std::fmt;
+
+/// A header along with the content it owns.
+///
+/// Sub-sections are stored as [Section](../node/enum.Node.html#variant.Section)
+/// nodes inside of the children. The header levels are derived from the
+/// nesting depth: a sub-section is always one level below its parent.
+///
+/// Sections are usually created using
+/// [Markdown::section](../markdown/struct.Markdown.html#method.section).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Section {
+    /// The header of the section.
+    pub header: Header,
+    /// The elements and sub-sections of the section.
+    pub children: Vec<Node>,
+}
+
+impl Section {
+    /// Creates a new empty section with a level 1 header.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            header: Header::from(title, 1usize),
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns the title of the section.
+    pub fn title(&self) -> &str {
+        &self.header.text
+    }
+
+    /// Returns the header level of the section.
+    pub fn level(&self) -> &HeaderLevel {
+        &self.header.level
+    }
+
+    /// Moves the section to the given level, re-leveling all sub-sections so
+    /// they keep their relative depth.
+    ///
+    /// Levels that would go past six are clamped.
+    pub fn set_level(&mut self, level: impl Into<HeaderLevel>) -> &mut Self {
+        let level = level.into();
+        let delta = level.value() as isize - self.header.level.value() as isize;
+        self.shift(delta);
+        self
+    }
+
+    fn shift(&mut self, delta: isize) {
+        self.header.level = self.header.level.shifted_clamped(delta);
+        for child in &mut self.children {
+            if let Node::Section(section) = child {
+                section.shift(delta);
+            }
+        }
+    }
+
+    /// Returns an iterator over the direct sub-sections.
+    pub fn sections(&self) -> impl Iterator<Item = &Section> {
+        self.children.iter().filter_map(|child| match child {
+            Node::Section(section) => Some(section),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator that allows modifying the direct sub-sections.
+    pub fn sections_mut(&mut self) -> impl Iterator<Item = &mut Section> {
+        self.children.iter_mut().filter_map(|child| match child {
+            Node::Section(section) => Some(section),
+            _ => None,
+        })
+    }
+
+    /// Returns the nested sub-section found by following the titles of
+    /// `path`, or this section if `path` is empty.
+    pub fn find_section<I, S>(&self, path: I) -> Option<&Section>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut section = self;
+        for title in path {
+            section = section
+                .sections()
+                .find(|section| section.title() == title.as_ref())?;
+        }
+        Some(section)
+    }
+
+    /// Returns the nested sub-section found by following the titles of
+    /// `path` mutably, or this section if `path` is empty.
+    pub fn section_mut<I, S>(&mut self, path: I) -> Option<&mut Section>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut section = self;
+        for title in path {
+            section = section
+                .sections_mut()
+                .find(|section| section.title() == title.as_ref())?;
+        }
+        Some(section)
+    }
+
+    /// Adds a sub-section one level below this section.
+    ///
+    /// # Arguments
+    ///
+    /// - `title`: The sub-section's title.
+    /// - `build`: Called with the new sub-section to add its content.
+    pub fn section(
+        &mut self,
+        title: impl Into<String>,
+        build: impl FnOnce(&mut Section),
+    ) -> &mut Self {
+        let mut section = Section::new(title);
+        section.header.level = self.header.level.shifted_clamped(1);
+        build(&mut section);
+        self.children.push(Node::Section(section));
+        self
+    }
+
+    /// Adds an existing section as a sub-section, re-leveling it to sit one
+    /// level below this section.
+    pub fn add_section(&mut self, mut section: Section) -> &mut Self {
+        section.set_level(self.header.level.shifted_clamped(1));
+        self.children.push(Node::Section(section));
+        self
+    }
+
+    /// Adds any generic markdown element to the section.
+    ///
+    /// Sections are added like using
+    /// [add_section](struct.Section.html#method.add_section), so they are
+    /// re-leveled to sit one level below this section.
+    pub fn add(&mut self, element: impl MarkdownElement + 'static) -> &mut Self {
+        match Node::from_element(element) {
+            Node::Section(section) => self.add_section(section),
+            node => {
+                self.children.push(node);
+                self
+            }
+        }
+    }
+
+    /// Adds a paragraph to the section.
+    pub fn paragraph(&mut self, text: impl Into<String>) -> &mut Self {
+        self.children.push(Node::Paragraph(Paragraph::from(text)));
+        self
+    }
+
+    /// Adds a list to the section.
+    pub fn list(&mut self, list: List) -> &mut Self {
+        self.children.push(Node::List(list));
+        self
+    }
+
+    /// Adds a link to the section.
+    pub fn link(&mut self, link: Link) -> &mut Self {
+        self.children.push(Node::Link(link));
+        self
+    }
+
+    /// Adds an image to the section.
+    pub fn image(&mut self, image: Image) -> &mut Self {
+        self.children.push(Node::Image(image));
+        self
+    }
+
+    /// Adds a block quote to the section.
+    pub fn quote(&mut self, quote: Quote) -> &mut Self {
+        self.children.push(Node::Quote(quote));
+        self
+    }
+
+    /// Renders the section for the given flavor.
+    pub fn render_with(&self, flavor: Flavor) -> String {
+        self.render_blocks(flavor, false)
+    }
+
+    /// Renders the section, separating its blocks like a compact document if
+    /// `compact` is set.
+    pub(crate) fn render_blocks(&self, flavor: Flavor, compact: bool) -> String {
+        let blocks = std::iter::once(self.header.render_with(flavor)).chain(
+            self.children
+                .iter()
+                .map(|child| child.render_block(flavor, compact)),
+        );
+
+        join_blocks(blocks, compact)
+    }
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render_with(Flavor::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn install() -> Section {
+        let mut section = Section::new("Install");
+        section
+            .paragraph("Pick your platform.")
+            .section("Linux", |s| {
+                s.paragraph("Use your package manager.")
+                    .section("Arch", |s| {
+                        s.paragraph("pacman");
+                    });
+            })
+            .section("Windows", |s| {
+                s.paragraph("Use the installer.");
+            });
+        section
+    }
+
+    #[test]
+    fn test_section_levels_follow_depth() {
+        assert_eq!(
+            install().render(),
+            "# Install\n\nPick your platform.\n\n## Linux\n\nUse your package manager.\n\n\
+            ### Arch\n\npacman\n\n## Windows\n\nUse the installer.\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_section() {
+        assert_eq!(Section::new("Title").render(), "# Title\n");
+    }
+
+    #[test]
+    fn test_section_levels_are_clamped() {
+        let mut section = Section::new("1");
+        section.set_level(5usize).section("6", |s| {
+            s.section("7", |_| {});
+        });
+
+        assert_eq!(section.render(), "##### 1\n\n###### 6\n\n###### 7\n");
+    }
+
+    #[test]
+    fn test_add_section_relevels() {
+        let mut section = Section::new("Guides");
+        section.set_level(2usize).add_section(install());
+
+        let install = section.find_section(["Install"]).unwrap();
+        assert_eq!(install.level().value(), 3);
+        assert_eq!(
+            install
+                .find_section(["Linux", "Arch"])
+                .unwrap()
+                .level()
+                .value(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_add_relevels_sections() {
+        let mut section = Section::new("A");
+        section.add(Section::new("B"));
+
+        assert_eq!(section.render(), "# A\n\n## B\n");
+    }
+
+    #[test]
+    fn test_find_section() {
+        let section = install();
+        assert_eq!(
+            section.find_section(["Linux", "Arch"]).unwrap().title(),
+            "Arch"
+        );
+        assert_eq!(
+            section.find_section(Vec::<&str>::new()).unwrap().title(),
+            "Install"
+        );
+        assert!(section.find_section(["Mac"]).is_none());
+    }
+
+    #[test]
+    fn test_section_mut() {
+        let mut section = install();
+        section
+            .section_mut(["Windows"])
+            .unwrap()
+            .paragraph("Or use winget.");
+
+        assert!(section
+            .render()
+            .ends_with("## Windows\n\nUse the installer.\n\nOr use winget.\n"));
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+
+/// The alignment of a table column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Alignment {
+    /// No explicit alignment, usually rendered left aligned.
+    #[default]
+    None,
+    /// Left aligned, `:--`.
+    Left,
+    /// Centered, `:-:`.
+    Center,
+    /// Right aligned, `--:`.
+    Right,
+}
+
+impl Alignment {
+    /// Returns whether no explicit alignment is set.
+    pub fn is_none(&self) -> bool {
+        *self == Self::None
+    }
+}
+
+/// A markdown table.
+///
+/// Rows with fewer cells than there are headers are padded with empty cells.
+/// Pipes inside of cells are escaped and line breaks are replaced by `<br>`.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Table {
+    /// The column headers.
+    pub headers: Vec<String>,
+    /// The rows, each containing one cell per column.
+    pub rows: Vec<Vec<String>>,
+    /// The alignment of each column. Missing columns are not aligned.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub alignments: Vec<Alignment>,
+}
+
+impl Table {
+    /// Creates a new empty `Table`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `Table` with the given headers and no rows.
+    pub fn from(headers: Vec<impl Into<String>>) -> Self {
+        Self {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: Vec::new(),
+            alignments: Vec::new(),
+        }
+    }
+
+    /// Creates a new `Table` with the given headers and rows.
+    pub fn with(headers: Vec<impl Into<String>>, rows: Vec<Vec<impl Into<String>>>) -> Self {
+        let mut table = Self::from(headers);
+        for row in rows {
+            table.add_row(row);
+        }
+        table
+    }
+
+    /// Adds a row to the table.
+    pub fn add_row(&mut self, row: Vec<impl Into<String>>) -> &mut Self {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the alignment of a column.
+    ///
+    /// # Arguments
+    ///
+    /// - `column`: The index of the column, starting at zero.
+    /// - `alignment`: The alignment to use.
+    pub fn align(&mut self, column: usize, alignment: Alignment) -> &mut Self {
+        if self.alignments.len() <= column {
+            self.alignments.resize(column + 1, Alignment::None);
+        }
+        self.alignments[column] = alignment;
+        self
+    }
+
+    /// Returns the alignment of a column.
+    pub fn alignment(&self, column: usize) -> Alignment {
+        self.alignments.get(column).copied().unwrap_or_default()
+    }
+}
+
+/// Escapes a cell so it cannot break the table.
+fn escape_cell(cell: &str) -> String {
+    cell.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.headers.is_empty() {
+            return Ok(());
+        }
+
+        let columns = self.headers.len();
+        let headers: Vec<String> = self.headers.iter().map(|cell| escape_cell(cell)).collect();
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                (0..columns)
+                    .map(|index| {
+                        row.get(index)
+                            .map_or_else(String::new, |cell| escape_cell(cell))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let widths: Vec<usize> = (0..columns)
+            .map(|index| {
+                rows.iter()
+                    .map(|row| row[index].chars().count())
+                    .chain(std::iter::once(headers[index].chars().count()))
+                    .max()
+                    .unwrap_or(0)
+                    .max(3)
+            })
+            .collect();
+
+        let write_row = |f: &mut fmt::Formatter<'_>, cells: &[String]| {
+            for (index, (cell, width)) in cells.iter().zip(&widths).enumerate() {
+                let padding = " ".repeat(width - cell.chars().count());
+                if self.alignment(index) == Alignment::Right {
+                    write!(f, "| {}{} ", padding, cell)?;
+                } else {
+                    write!(f, "| {}{} ", cell, padding)?;
+                }
+            }
+            writeln!(f, "|")
+        };
+
+        write_row(f, &headers)?;
+        let separators: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(index, width)| match self.alignment(index) {
+                Alignment::None => "-".repeat(*width),
+                Alignment::Left => format!(":{}", "-".repeat(width - 1)),
+                Alignment::Center => format!(":{}:", "-".repeat(width - 2)),
+                Alignment::Right => format!("{}:", "-".repeat(width - 1)),
+            })
+            .collect();
+        write_row(f, &separators)?;
+        for row in &rows {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MarkdownElement;
+
+    #[test]
+    fn test_table() {
+        let table = Table::with(
+            vec!["Name", "Age"],
+            vec![vec!["Alice", "30"], vec!["Bob", "4"]],
+        );
+
+        assert_eq!(
+            table.render(),
+            "| Name  | Age |\n| ----- | --- |\n| Alice | 30  |\n| Bob   | 4   |\n"
+        );
+    }
+
+    #[test]
+    fn test_table_pads_short_rows() {
+        let mut table = Table::from(vec!["a", "b"]);
+        table.add_row(vec!["1"]);
+
+        assert_eq!(
+            table.render(),
+            "| a   | b   |\n| --- | --- |\n| 1   |     |\n"
+        );
+    }
+
+    #[test]
+    fn test_table_escapes_cells() {
+        let table = Table::with(vec!["Cell"], vec![vec!["a|b\nc"]]);
+        assert_eq!(
+            table.render(),
+            "| Cell      |\n| --------- |\n| a\\|b<br>c |\n"
+        );
+    }
+
+    #[test]
+    fn test_table_alignment() {
+        let mut table = Table::with(
+            vec!["Left", "Center", "Right"],
+            vec![vec!["a", "b", "1"], vec!["c", "d", "100"]],
+        );
+        table
+            .align(0, Alignment::Left)
+            .align(1, Alignment::Center)
+            .align(2, Alignment::Right);
+
+        assert_eq!(
+            table.render(),
+            "| Left | Center | Right |\n\
+            | :--- | :----: | ----: |\n\
+            | a    | b      |     1 |\n\
+            | c    | d      |   100 |\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_table() {
+        assert_eq!(Table::new().render(), "");
+    }
+}
@@ -0,0 +1,525 @@
+//! Contains traits for walking and rewriting markdown documents.
+//!
+//! [Visitor](trait.Visitor.html) and [VisitorMut](trait.VisitorMut.html)
+//! provide a method for each node type. The default implementations recurse
+//! into nested nodes using the `walk_*` functions, so implementors only need to
+//! override the methods of the nodes they are interested in. When overriding a
+//! method of a container node (e.g. `visit_list`), call the matching `walk_*`
+//! function to keep recursing into its children.
+
+use crate::{
+    traits::MarkdownElement,
+    types::{
+        autolink::Autolink,
+        checkbox::{Checkbox, Task},
+        definition_list::DefinitionList,
+        details::Details,
+        diagram::Diagram,
+        header::Header,
+        image::Image,
+        include::Include,
+        link::Link,
+        list::List,
+        math::{InlineMath, MathBlock},
+        node::Node,
+        paragraph::Paragraph,
+        quote::Quote,
+        section::Section,
+        table::Table,
+    },
+};
+
+/// Walks a document without modifying it.
+///
+/// Use [Markdown::walk](../types/markdown/struct.Markdown.html#method.walk) to
+/// run a visitor over a whole document.
+pub trait Visitor {
+    /// Visits any node, dispatching to the method of its type.
+    fn visit_node(&mut self, node: &Node) {
+        walk_node(self, node);
+    }
+
+    /// Visits a header.
+    fn visit_header(&mut self, _header: &Header) {}
+
+    /// Visits a paragraph and, by default, its inline elements.
+    fn visit_paragraph(&mut self, paragraph: &Paragraph) {
+        walk_paragraph(self, paragraph);
+    }
+
+    /// Visits a link.
+    fn visit_link(&mut self, _link: &Link) {}
+
+    /// Visits an image.
+    fn visit_image(&mut self, _image: &Image) {}
+
+    /// Visits a list and, by default, its items.
+    fn visit_list(&mut self, list: &List) {
+        walk_list(self, list);
+    }
+
+    /// Visits a checkbox.
+    fn visit_checkbox(&mut self, _checkbox: &Checkbox) {}
+
+    /// Visits a task and, by default, its subtasks.
+    fn visit_task(&mut self, task: &Task) {
+        walk_task(self, task);
+    }
+
+    /// Visits a quote and, by default, its children.
+    fn visit_quote(&mut self, quote: &Quote) {
+        walk_quote(self, quote);
+    }
+
+    /// Visits an included document and, by default, its elements.
+    fn visit_include(&mut self, include: &Include) {
+        walk_include(self, include);
+    }
+
+    /// Visits a section and, by default, its header and children.
+    fn visit_section(&mut self, section: &Section) {
+        walk_section(self, section);
+    }
+
+    /// Visits a table.
+    fn visit_table(&mut self, _table: &Table) {}
+
+    /// Visits a definition list and, by default, its definitions.
+    fn visit_definition_list(&mut self, list: &DefinitionList) {
+        walk_definition_list(self, list);
+    }
+
+    /// Visits collapsible details and, by default, their children.
+    fn visit_details(&mut self, details: &Details) {
+        walk_details(self, details);
+    }
+
+    /// Visits a math block.
+    fn visit_math_block(&mut self, _math: &MathBlock) {}
+
+    /// Visits an inline math span.
+    fn visit_inline_math(&mut self, _math: &InlineMath) {}
+
+    /// Visits a diagram.
+    fn visit_diagram(&mut self, _diagram: &Diagram) {}
+
+    /// Visits an autolink.
+    fn visit_autolink(&mut self, _link: &Autolink) {}
+
+    /// Visits raw markdown text.
+    fn visit_text(&mut self, _text: &str) {}
+
+    /// Visits a custom element.
+    fn visit_custom(&mut self, _element: &dyn MarkdownElement) {}
+}
+
+/// Dispatches the node to the visitor method of its type.
+pub fn walk_node<V: Visitor + ?Sized>(visitor: &mut V, node: &Node) {
+    match node {
+        Node::Header(header) => visitor.visit_header(header),
+        Node::Paragraph(paragraph) => visitor.visit_paragraph(paragraph),
+        Node::Link(link) => visitor.visit_link(link),
+        Node::Image(image) => visitor.visit_image(image),
+        Node::List(list) => visitor.visit_list(list),
+        Node::Checkbox(checkbox) => visitor.visit_checkbox(checkbox),
+        Node::Task(task) => visitor.visit_task(task),
+        Node::Quote(quote) => visitor.visit_quote(quote),
+        Node::Include(include) => visitor.visit_include(include),
+        Node::Section(section) => visitor.visit_section(section),
+        Node::Table(table) => visitor.visit_table(table),
+        Node::DefinitionList(list) => visitor.visit_definition_list(list),
+        Node::Details(details) => visitor.visit_details(details),
+        Node::MathBlock(math) => visitor.visit_math_block(math),
+        Node::InlineMath(math) => visitor.visit_inline_math(math),
+        Node::Diagram(diagram) => visitor.visit_diagram(diagram),
+        Node::Autolink(link) => visitor.visit_autolink(link),
+        Node::Text(text) => visitor.visit_text(text),
+        Node::Custom(element) => visitor.visit_custom(element.as_ref()),
+    }
+}
+
+/// Visits every inline element of the paragraph.
+pub fn walk_paragraph<V: Visitor + ?Sized>(visitor: &mut V, paragraph: &Paragraph) {
+    for child in &paragraph.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Visits every item of the list.
+pub fn walk_list<V: Visitor + ?Sized>(visitor: &mut V, list: &List) {
+    for item in &list.items {
+        visitor.visit_node(item);
+    }
+}
+
+/// Visits the checkbox and the subtasks of the task.
+pub fn walk_task<V: Visitor + ?Sized>(visitor: &mut V, task: &Task) {
+    visitor.visit_checkbox(&task.checkbox);
+    visitor.visit_list(&task.subtasks);
+}
+
+/// Visits every child of the quote.
+pub fn walk_quote<V: Visitor + ?Sized>(visitor: &mut V, quote: &Quote) {
+    for child in &quote.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Visits every element of the included document.
+pub fn walk_include<V: Visitor + ?Sized>(visitor: &mut V, include: &Include) {
+    for node in &include.document.elements {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits the header and every child of the section.
+pub fn walk_section<V: Visitor + ?Sized>(visitor: &mut V, section: &Section) {
+    visitor.visit_header(&section.header);
+    for child in &section.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Visits every block of every definition of the list.
+pub fn walk_definition_list<V: Visitor + ?Sized>(visitor: &mut V, list: &DefinitionList) {
+    for item in &list.items {
+        for node in item.definitions.iter().flatten() {
+            visitor.visit_node(node);
+        }
+    }
+}
+
+/// Visits every child of the details.
+pub fn walk_details<V: Visitor + ?Sized>(visitor: &mut V, details: &Details) {
+    for child in &details.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Walks a document, allowing to modify every node.
+///
+/// Use [Markdown::walk_mut](../types/markdown/struct.Markdown.html#method.walk_mut)
+/// to run a visitor over a whole document.
+pub trait VisitorMut {
+    /// Visits any node, dispatching to the method of its type.
+    ///
+    /// Override this method to replace whole nodes.
+    fn visit_node_mut(&mut self, node: &mut Node) {
+        walk_node_mut(self, node);
+    }
+
+    /// Visits a header.
+    fn visit_header_mut(&mut self, _header: &mut Header) {}
+
+    /// Visits a paragraph and, by default, its inline elements.
+    fn visit_paragraph_mut(&mut self, paragraph: &mut Paragraph) {
+        walk_paragraph_mut(self, paragraph);
+    }
+
+    /// Visits a link.
+    fn visit_link_mut(&mut self, _link: &mut Link) {}
+
+    /// Visits an image.
+    fn visit_image_mut(&mut self, _image: &mut Image) {}
+
+    /// Visits a list and, by default, its items.
+    fn visit_list_mut(&mut self, list: &mut List) {
+        walk_list_mut(self, list);
+    }
+
+    /// Visits a checkbox.
+    fn visit_checkbox_mut(&mut self, _checkbox: &mut Checkbox) {}
+
+    /// Visits a task and, by default, its subtasks.
+    fn visit_task_mut(&mut self, task: &mut Task) {
+        walk_task_mut(self, task);
+    }
+
+    /// Visits a quote and, by default, its children.
+    fn visit_quote_mut(&mut self, quote: &mut Quote) {
+        walk_quote_mut(self, quote);
+    }
+
+    /// Visits an included document and, by default, its elements.
+    fn visit_include_mut(&mut self, include: &mut Include) {
+        walk_include_mut(self, include);
+    }
+
+    /// Visits a section and, by default, its header and children.
+    fn visit_section_mut(&mut self, section: &mut Section) {
+        walk_section_mut(self, section);
+    }
+
+    /// Visits a table.
+    fn visit_table_mut(&mut self, _table: &mut Table) {}
+
+    /// Visits a definition list and, by default, its definitions.
+    fn visit_definition_list_mut(&mut self, list: &mut DefinitionList) {
+        walk_definition_list_mut(self, list);
+    }
+
+    /// Visits collapsible details and, by default, their children.
+    fn visit_details_mut(&mut self, details: &mut Details) {
+        walk_details_mut(self, details);
+    }
+
+    /// Visits a math block.
+    fn visit_math_block_mut(&mut self, _math: &mut MathBlock) {}
+
+    /// Visits an inline math span.
+    fn visit_inline_math_mut(&mut self, _math: &mut InlineMath) {}
+
+    /// Visits a diagram.
+    fn visit_diagram_mut(&mut self, _diagram: &mut Diagram) {}
+
+    /// Visits an autolink.
+    fn visit_autolink_mut(&mut self, _link: &mut Autolink) {}
+
+    /// Visits raw markdown text.
+    fn visit_text_mut(&mut self, _text: &mut String) {}
+
+    /// Visits a custom element.
+    fn visit_custom_mut(&mut self, _element: &mut Box<dyn MarkdownElement>) {}
+}
+
+/// Dispatches the node to the mutable visitor method of its type.
+pub fn walk_node_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Node) {
+    match node {
+        Node::Header(header) => visitor.visit_header_mut(header),
+        Node::Paragraph(paragraph) => visitor.visit_paragraph_mut(paragraph),
+        Node::Link(link) => visitor.visit_link_mut(link),
+        Node::Image(image) => visitor.visit_image_mut(image),
+        Node::List(list) => visitor.visit_list_mut(list),
+        Node::Checkbox(checkbox) => visitor.visit_checkbox_mut(checkbox),
+        Node::Task(task) => visitor.visit_task_mut(task),
+        Node::Quote(quote) => visitor.visit_quote_mut(quote),
+        Node::Include(include) => visitor.visit_include_mut(include),
+        Node::Section(section) => visitor.visit_section_mut(section),
+        Node::Table(table) => visitor.visit_table_mut(table),
+        Node::DefinitionList(list) => visitor.visit_definition_list_mut(list),
+        Node::Details(details) => visitor.visit_details_mut(details),
+        Node::MathBlock(math) => visitor.visit_math_block_mut(math),
+        Node::InlineMath(math) => visitor.visit_inline_math_mut(math),
+        Node::Diagram(diagram) => visitor.visit_diagram_mut(diagram),
+        Node::Autolink(link) => visitor.visit_autolink_mut(link),
+        Node::Text(text) => visitor.visit_text_mut(text),
+        Node::Custom(element) => visitor.visit_custom_mut(element),
+    }
+}
+
+/// Visits every inline element of the paragraph mutably.
+pub fn walk_paragraph_mut<V: VisitorMut + ?Sized>(visitor: &mut V, paragraph: &mut Paragraph) {
+    for child in &mut paragraph.children {
+        visitor.visit_node_mut(child);
+    }
+}
+
+/// Visits every item of the list mutably.
+pub fn walk_list_mut<V: VisitorMut + ?Sized>(visitor: &mut V, list: &mut List) {
+    for item in &mut list.items {
+        visitor.visit_node_mut(item);
+    }
+}
+
+/// Visits the checkbox and the subtasks of the task mutably.
+pub fn walk_task_mut<V: VisitorMut + ?Sized>(visitor: &mut V, task: &mut Task) {
+    visitor.visit_checkbox_mut(&mut task.checkbox);
+    visitor.visit_list_mut(&mut task.subtasks);
+}
+
+/// Visits every child of the quote mutably.
+pub fn walk_quote_mut<V: VisitorMut + ?Sized>(visitor: &mut V, quote: &mut Quote) {
+    for child in &mut quote.children {
+        visitor.visit_node_mut(child);
+    }
+}
+
+/// Visits every element of the included document mutably.
+pub fn walk_include_mut<V: VisitorMut + ?Sized>(visitor: &mut V, include: &mut Include) {
+    for node in &mut include.document.elements {
+        visitor.visit_node_mut(node);
+    }
+}
+
+/// Visits the header and every child of the section mutably.
+pub fn walk_section_mut<V: VisitorMut + ?Sized>(visitor: &mut V, section: &mut Section) {
+    visitor.visit_header_mut(&mut section.header);
+    for child in &mut section.children {
+        visitor.visit_node_mut(child);
+    }
+}
+
+/// Visits every block of every definition of the list mutably.
+pub fn walk_definition_list_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    list: &mut DefinitionList,
+) {
+    for item in &mut list.items {
+        for node in item.definitions.iter_mut().flatten() {
+            visitor.visit_node_mut(node);
+        }
+    }
+}
+
+/// Visits every child of the details mutably.
+pub fn walk_details_mut<V: VisitorMut + ?Sized>(visitor: &mut V, details: &mut Details) {
+    for child in &mut details.children {
+        visitor.visit_node_mut(child);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HeaderLevel, ImageBuilder, LinkBuilder, ListBuilder, Markdown};
+
+    struct AbsoluteLinks(&'static str);
+
+    impl VisitorMut for AbsoluteLinks {
+        fn visit_link_mut(&mut self, link: &mut Link) {
+            if !link.url.contains("://") {
+                link.url = format!("{}/{}", self.0, link.url.trim_start_matches('/'));
+            }
+        }
+    }
+
+    struct ShiftHeaders;
+
+    impl VisitorMut for ShiftHeaders {
+        fn visit_header_mut(&mut self, header: &mut Header) {
+            header.level = HeaderLevel::from(header.level.value() + 1);
+        }
+    }
+
+    struct LowercaseImages;
+
+    impl VisitorMut for LowercaseImages {
+        fn visit_image_mut(&mut self, image: &mut Image) {
+            image.url = image.url.to_lowercase();
+        }
+    }
+
+    #[derive(Default)]
+    struct CountWords(usize);
+
+    impl Visitor for CountWords {
+        fn visit_paragraph(&mut self, paragraph: &Paragraph) {
+            self.0 += paragraph.text().split_whitespace().count();
+        }
+
+        fn visit_text(&mut self, text: &str) {
+            self.0 += text.split_whitespace().count();
+        }
+    }
+
+    fn link(url: &str, text: &str) -> Link {
+        LinkBuilder::new().url(url).text(text).build()
+    }
+
+    #[test]
+    fn test_rewrite_relative_links() {
+        let mut doc = Markdown::new();
+        doc.link(link("/docs", "Docs"))
+            .link(link("https://example.com", "Example"))
+            .list(
+                ListBuilder::new()
+                    .append(link("guide", "Guide"))
+                    .unordered(),
+            )
+            .quote(Quote::with(vec![link("faq", "FAQ")]));
+
+        doc.walk_mut(&mut AbsoluteLinks("https://rust-lang.org"));
+
+        let urls: Vec<&str> = doc.links().map(|link| link.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://rust-lang.org/docs",
+                "https://example.com",
+                "https://rust-lang.org/guide",
+                "https://rust-lang.org/faq"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rewrite_updates_footers() {
+        let mut doc = Markdown::new();
+        doc.link(
+            LinkBuilder::new()
+                .url("/docs")
+                .text("Docs")
+                .footer()
+                .build(),
+        );
+
+        doc.walk_mut(&mut AbsoluteLinks("https://rust-lang.org"));
+
+        assert_eq!(
+            doc.render(),
+            "[Docs][Docs]\n\n[Docs]: https://rust-lang.org/docs\n"
+        );
+    }
+
+    #[test]
+    fn test_shift_headers() {
+        let mut doc = Markdown::new();
+        doc.h1("Title").h2("Section");
+
+        doc.walk_mut(&mut ShiftHeaders);
+
+        assert_eq!(doc.render(), "## Title\n\n### Section\n");
+    }
+
+    #[test]
+    fn test_lowercase_image_urls() {
+        let mut doc = Markdown::new();
+        doc.image(
+            ImageBuilder::new()
+                .url("https://example.com/Cat.PNG")
+                .build(),
+        );
+
+        doc.walk_mut(&mut LowercaseImages);
+
+        assert_eq!(
+            doc.images().next().unwrap().url,
+            "https://example.com/cat.png"
+        );
+    }
+
+    #[test]
+    fn test_visitor_recurses() {
+        let mut doc = Markdown::new();
+        doc.paragraph("one two")
+            .list(ListBuilder::new().append("three").unordered())
+            .quote(Quote::with(vec![Paragraph::from("four five")]));
+
+        let mut counter = CountWords::default();
+        doc.walk(&mut counter);
+
+        assert_eq!(counter.0, 5);
+    }
+
+    #[test]
+    fn test_overridden_container_stops_recursion() {
+        struct SkipLists(CountWords);
+
+        impl Visitor for SkipLists {
+            fn visit_list(&mut self, _list: &List) {}
+
+            fn visit_text(&mut self, text: &str) {
+                self.0.visit_text(text);
+            }
+        }
+
+        let mut doc = Markdown::new();
+        doc.add("one")
+            .list(ListBuilder::new().append("two").unordered());
+
+        let mut visitor = SkipLists(CountWords::default());
+        doc.walk(&mut visitor);
+
+        assert_eq!(visitor.0 .0, 1);
+    }
+}